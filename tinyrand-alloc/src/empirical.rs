@@ -0,0 +1,151 @@
+//! Sampling from [empirical distributions](https://en.wikipedia.org/wiki/Empirical_distribution_function),
+//! built from observed data or histograms.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+use tinyrand::{Distribution, Rand};
+
+/// How values are generated within a bucket once it has been selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interpolation {
+    /// Each bucket is treated as a single value. Samples are drawn from the observed values
+    /// only, or from the lower bounds of histogram buckets.
+    #[default]
+    None,
+
+    /// Values are spread uniformly within each bucket, so that the cumulative distribution
+    /// function is piecewise linear. For raw samples, the buckets are the intervals between
+    /// consecutive (sorted) observations.
+    Linear,
+}
+
+/// An empirical distribution, which replays observed data.
+///
+/// A bucket is selected with a probability proportional to its weight, using a single call
+/// to [`Rand::next_lim_u64`] over the total weight followed by a binary search of the
+/// cumulative weights. Because the weights are integers, bucket selection is exact
+/// (i.e., free of rounding bias). When interpolating, one additional [`Rand::next_f64`] is drawn
+/// to place the value within its bucket.
+///
+/// # Examples
+/// ```
+/// use tinyrand::{Distribution, StdRand};
+/// use tinyrand_alloc::{Empirical, Interpolation};
+/// // request sizes, in bytes, captured from production
+/// let dist = Empirical::from_histogram([
+///     (0.0..1_024.0, 700),
+///     (1_024.0..4_096.0, 250),
+///     (4_096.0..65_536.0, 50),
+/// ], Interpolation::Linear);
+/// let mut rand = StdRand::default();
+/// let size = dist.sample(&mut rand);
+/// assert!((0.0..65_536.0).contains(&size));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Empirical {
+    buckets: Vec<Range<f64>>,
+    cumulative: Vec<u64>,
+    total_weight: u64,
+    interpolation: Interpolation,
+}
+
+impl Empirical {
+    /// Creates an [`Empirical`] distribution from raw samples, each of which carries an equal weight.
+    ///
+    /// Without interpolation, every sample is replayed verbatim. With linear interpolation, the
+    /// distribution is continuous between the smallest and largest samples, and each interval
+    /// between consecutive samples is equally likely.
+    ///
+    /// # Panics
+    /// If `samples` is empty, or if any of the samples is not finite.
+    pub fn from_samples(samples: &[f64], interpolation: Interpolation) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+        for &sample in samples {
+            assert!(sample.is_finite(), "sample ({sample}) must be finite");
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        let buckets = match interpolation {
+            Interpolation::Linear if sorted.len() > 1 => sorted
+                .windows(2)
+                .map(|pair| pair[0]..pair[1])
+                .collect::<Vec<_>>(),
+            _ => sorted.into_iter().map(|sample| sample..sample).collect(),
+        };
+        let total_weight = buckets.len() as u64;
+        let cumulative = (1..=total_weight).collect();
+        Self {
+            buckets,
+            cumulative,
+            total_weight,
+            interpolation,
+        }
+    }
+
+    /// Creates an [`Empirical`] distribution from a histogram, comprising `(bucket, weight)` pairs.
+    /// The buckets need not be sorted, contiguous or of equal width. Buckets with a zero weight
+    /// are never sampled.
+    ///
+    /// Without interpolation, a sample takes the lower bound of the selected bucket. With linear
+    /// interpolation, a sample is uniformly distributed within the selected bucket.
+    ///
+    /// # Panics
+    /// If none of the buckets has a nonzero weight, if any of the bounds is not finite, if any
+    /// bucket's upper bound is less than its lower bound, or if the weights sum to more than `u64::MAX`.
+    pub fn from_histogram(
+        histogram: impl IntoIterator<Item = (Range<f64>, u64)>,
+        interpolation: Interpolation,
+    ) -> Self {
+        let mut buckets = Vec::new();
+        let mut cumulative = Vec::new();
+        let mut total_weight = 0u64;
+        for (bucket, weight) in histogram {
+            assert!(bucket.start.is_finite(), "bucket start ({}) must be finite", bucket.start);
+            assert!(bucket.end.is_finite(), "bucket end ({}) must be finite", bucket.end);
+            assert!(
+                bucket.start <= bucket.end,
+                "bucket end ({}) cannot be less than start ({})",
+                bucket.end,
+                bucket.start
+            );
+            if weight > 0 {
+                total_weight = total_weight.checked_add(weight).expect("total weight overflows u64");
+                buckets.push(bucket);
+                cumulative.push(total_weight);
+            }
+        }
+        assert!(!buckets.is_empty(), "all weights are zero");
+        Self {
+            buckets,
+            cumulative,
+            total_weight,
+            interpolation,
+        }
+    }
+
+    /// The sum of the bucket weights.
+    pub fn total_weight(&self) -> u64 {
+        self.total_weight
+    }
+
+    /// The interpolation method.
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+}
+
+impl Distribution<f64> for Empirical {
+    fn sample(&self, rand: &mut impl Rand) -> f64 {
+        let point = rand.next_lim_u64(self.total_weight);
+        let index = self.cumulative.partition_point(|&cumulative| cumulative <= point);
+        let bucket = &self.buckets[index];
+        match self.interpolation {
+            Interpolation::None => bucket.start,
+            Interpolation::Linear => bucket.start + rand.next_f64() * (bucket.end - bucket.start),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
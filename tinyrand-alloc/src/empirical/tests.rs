@@ -0,0 +1,121 @@
+use crate::{Empirical, Interpolation, Mock};
+use alloc::vec::Vec;
+use tinyrand::{Distribution, Wyrand};
+
+#[test]
+fn samples_without_interpolation() {
+    let dist = Empirical::from_samples(&[3.0, 1.0, 2.0, 2.0], Interpolation::None);
+    assert_eq!(4, dist.total_weight());
+    assert_eq!(Interpolation::None, dist.interpolation());
+    let sampled = (0..4u128)
+        .map(|point| {
+            let mut rand = Mock::default().with_next_lim_u128(move |_, _| point);
+            dist.sample(&mut rand)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(&[1.0, 2.0, 2.0, 3.0], sampled.as_slice());
+}
+
+#[test]
+fn samples_with_interpolation() {
+    let dist = Empirical::from_samples(&[10.0, 0.0, 4.0], Interpolation::Linear);
+    assert_eq!(2, dist.total_weight());
+
+    // the first interval is [0, 4); the second is [4, 10)
+    let mut rand = Mock::default()
+        .with_next_lim_u128(|_, _| 0)
+        .with_next_u128(|_| u128::from(u64::MAX >> 1));
+    let sample = dist.sample(&mut rand);
+    assert!((sample - 2.0).abs() < 1e-9, "sampled {sample}");
+
+    let mut rand = Mock::default()
+        .with_next_lim_u128(|_, _| 1)
+        .with_next_u128(|_| 0);
+    assert_eq!(4.0, dist.sample(&mut rand));
+}
+
+#[test]
+fn single_sample_with_interpolation() {
+    let dist = Empirical::from_samples(&[42.0], Interpolation::Linear);
+    let mut rand = Wyrand::default();
+    for _ in 0..10 {
+        assert_eq!(42.0, dist.sample(&mut rand));
+    }
+}
+
+#[test]
+fn histogram_without_interpolation() {
+    let dist = Empirical::from_histogram([(10.0..20.0, 2), (0.0..10.0, 0), (20.0..30.0, 1)], Interpolation::None);
+    assert_eq!(3, dist.total_weight());
+    let sampled = (0..3u128)
+        .map(|point| {
+            let mut rand = Mock::default().with_next_lim_u128(move |_, _| point);
+            dist.sample(&mut rand)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(&[10.0, 10.0, 20.0], sampled.as_slice());
+}
+
+#[test]
+fn histogram_with_interpolation() {
+    let dist = Empirical::from_histogram([(0.0..1_024.0, 7), (1_024.0..4_096.0, 3)], Interpolation::Linear);
+    let mut rand = Wyrand::default();
+    let mut in_first = 0;
+    const N: u32 = 10_000;
+    for _ in 0..N {
+        let sample = dist.sample(&mut rand);
+        assert!((0.0..4_096.0).contains(&sample), "sampled {sample}");
+        if sample < 1_024.0 {
+            in_first += 1;
+        }
+    }
+    let fraction = f64::from(in_first) / f64::from(N);
+    assert!((fraction - 0.7).abs() < 0.02, "fraction={fraction}");
+}
+
+#[test]
+fn histogram_requests_exact_limit() {
+    let dist = Empirical::from_histogram([(0.0..1.0, u64::MAX - 1), (1.0..2.0, 1)], Interpolation::None);
+    let mut rand = Mock::default().with_next_lim_u128(|_, lim| {
+        assert_eq!(u128::from(u64::MAX), lim);
+        lim - 1
+    });
+    assert_eq!(1.0, dist.sample(&mut rand));
+}
+
+#[test]
+#[should_panic(expected = "no samples")]
+fn no_samples() {
+    Empirical::from_samples(&[], Interpolation::None);
+}
+
+#[test]
+#[should_panic(expected = "sample (NaN) must be finite")]
+fn nan_sample() {
+    Empirical::from_samples(&[1.0, f64::NAN], Interpolation::None);
+}
+
+#[test]
+#[should_panic(expected = "all weights are zero")]
+fn zero_weights() {
+    Empirical::from_histogram([(0.0..1.0, 0)], Interpolation::None);
+}
+
+#[test]
+#[should_panic(expected = "bucket end (0) cannot be less than start (1)")]
+fn inverted_bucket() {
+    #[allow(clippy::reversed_empty_ranges)]
+    Empirical::from_histogram([(1.0..0.0, 1)], Interpolation::None);
+}
+
+#[test]
+#[should_panic(expected = "bucket start (-inf) must be finite")]
+fn infinite_bucket() {
+    Empirical::from_histogram([(f64::NEG_INFINITY..0.0, 1)], Interpolation::None);
+}
+
+#[test]
+#[should_panic(expected = "total weight overflows u64")]
+fn weight_overflow() {
+    Empirical::from_histogram([(0.0..1.0, u64::MAX), (1.0..2.0, 1)], Interpolation::None);
+}
@@ -4,6 +4,14 @@
 
 extern crate alloc;
 
+pub mod empirical;
 pub mod mock;
+pub mod multivariate;
+pub mod sample;
+pub mod weighted;
 
-pub use mock::*;
\ No newline at end of file
+pub use empirical::{Empirical, Interpolation};
+pub use mock::*;
+pub use multivariate::{DirichletVec, MultinomialVec, UnitBallVec, UnitHypersphereVec};
+pub use sample::RandSample;
+pub use weighted::{DynamicWeighted, Weight, WeightError, WeightedAlias};
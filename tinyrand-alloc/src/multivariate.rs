@@ -0,0 +1,178 @@
+//! Multivariate distributions whose dimensions are only known at runtime.
+//!
+//! These mirror the fixed-size distributions in [`tinyrand::multivariate`], returning samples in a [`Vec`].
+
+use alloc::vec;
+use alloc::vec::Vec;
+use tinyrand::multivariate::{fill_dirichlet, fill_multinomial, fill_unit_ball, fill_unit_hypersphere};
+use tinyrand::{Distribution, Rand};
+
+/// The [Dirichlet distribution](https://en.wikipedia.org/wiki/Dirichlet_distribution), whose order
+/// is the number of concentration parameters.
+///
+/// # Examples
+/// ```
+/// use tinyrand::{Distribution, StdRand};
+/// use tinyrand_alloc::DirichletVec;
+/// let dist = DirichletVec::new(vec![1.0; 10]);
+/// let mut rand = StdRand::default();
+/// let sample = dist.sample(&mut rand);
+/// assert_eq!(10, sample.len());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirichletVec {
+    alpha: Vec<f64>,
+}
+
+impl DirichletVec {
+    /// Creates a new [`DirichletVec`] distribution with the given concentration parameters.
+    ///
+    /// # Panics
+    /// If `alpha` is empty, or if any of its elements is not a positive, finite number.
+    pub fn new(alpha: Vec<f64>) -> Self {
+        assert!(!alpha.is_empty(), "no concentration parameters");
+        for &alpha in &alpha {
+            assert!(alpha > 0f64, "shape ({alpha}) must be greater than 0");
+            assert!(alpha.is_finite(), "shape ({alpha}) must be finite");
+        }
+        Self { alpha }
+    }
+
+    /// The concentration parameters.
+    pub fn alpha(&self) -> &[f64] {
+        &self.alpha
+    }
+}
+
+impl Distribution<Vec<f64>> for DirichletVec {
+    fn sample(&self, rand: &mut impl Rand) -> Vec<f64> {
+        let mut sample = vec![0.0; self.alpha.len()];
+        fill_dirichlet(rand, &self.alpha, &mut sample);
+        sample
+    }
+}
+
+/// The [multinomial distribution](https://en.wikipedia.org/wiki/Multinomial_distribution) over
+/// `n` trials, where the number of categories is the number of weights.
+///
+/// # Examples
+/// ```
+/// use tinyrand::{Distribution, StdRand};
+/// use tinyrand_alloc::MultinomialVec;
+/// let dist = MultinomialVec::new(100, vec![1.0; 10]);
+/// let mut rand = StdRand::default();
+/// let counts = dist.sample(&mut rand);
+/// assert_eq!(100, counts.iter().sum::<u64>());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultinomialVec {
+    n: u64,
+    weights: Vec<f64>,
+}
+
+impl MultinomialVec {
+    /// Creates a new [`MultinomialVec`] distribution over `n` trials. The categories are chosen with
+    /// probabilities proportional to the given `weights`, which need not sum to 1.
+    ///
+    /// # Panics
+    /// If any of the weights is negative or not finite, or if all weights are zero (including when
+    /// there are no weights).
+    pub fn new(n: u64, weights: Vec<f64>) -> Self {
+        for &weight in &weights {
+            assert!(weight >= 0f64, "weight ({weight}) cannot be less than 0");
+            assert!(weight.is_finite(), "weight ({weight}) must be finite");
+        }
+        assert!(weights.iter().sum::<f64>() > 0f64, "all weights are zero");
+        Self { n, weights }
+    }
+
+    /// The number of trials.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// The weights of the categories.
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+}
+
+impl Distribution<Vec<u64>> for MultinomialVec {
+    fn sample(&self, rand: &mut impl Rand) -> Vec<u64> {
+        let mut counts = vec![0; self.weights.len()];
+        fill_multinomial(rand, self.n, &self.weights, &mut counts);
+        counts
+    }
+}
+
+/// The uniform distribution of points on the surface of the unit hypersphere in `dim` dimensions.
+///
+/// # Examples
+/// ```
+/// use tinyrand::{Distribution, StdRand};
+/// use tinyrand_alloc::UnitHypersphereVec;
+/// let mut rand = StdRand::default();
+/// let point = UnitHypersphereVec::new(5).sample(&mut rand);
+/// assert!((point.iter().map(|x| x * x).sum::<f64>() - 1.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnitHypersphereVec {
+    dim: usize,
+}
+
+impl UnitHypersphereVec {
+    /// Creates a new [`UnitHypersphereVec`] distribution in `dim` dimensions.
+    ///
+    /// # Panics
+    /// If `dim` is zero.
+    pub fn new(dim: usize) -> Self {
+        assert_ne!(0, dim, "zero dimensions");
+        Self { dim }
+    }
+
+    /// The number of dimensions.
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+}
+
+impl Distribution<Vec<f64>> for UnitHypersphereVec {
+    fn sample(&self, rand: &mut impl Rand) -> Vec<f64> {
+        let mut point = vec![0.0; self.dim];
+        fill_unit_hypersphere(rand, &mut point);
+        point
+    }
+}
+
+/// The uniform distribution of points inside the unit ball in `dim` dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnitBallVec {
+    dim: usize,
+}
+
+impl UnitBallVec {
+    /// Creates a new [`UnitBallVec`] distribution in `dim` dimensions.
+    ///
+    /// # Panics
+    /// If `dim` is zero.
+    pub fn new(dim: usize) -> Self {
+        assert_ne!(0, dim, "zero dimensions");
+        Self { dim }
+    }
+
+    /// The number of dimensions.
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+}
+
+impl Distribution<Vec<f64>> for UnitBallVec {
+    fn sample(&self, rand: &mut impl Rand) -> Vec<f64> {
+        let mut point = vec![0.0; self.dim];
+        fill_unit_ball(rand, &mut point);
+        point
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,93 @@
+use crate::{DirichletVec, MultinomialVec, UnitBallVec, UnitHypersphereVec};
+use alloc::vec;
+use tinyrand::{Distribution, Wyrand};
+
+fn norm_sq(point: &[f64]) -> f64 {
+    point.iter().map(|x| x * x).sum::<f64>()
+}
+
+#[test]
+fn dirichlet_on_simplex() {
+    let mut rand = Wyrand::default();
+    let dist = DirichletVec::new(vec![0.5, 1.0, 2.0]);
+    assert_eq!(&[0.5, 1.0, 2.0], dist.alpha());
+    for _ in 0..100 {
+        let sample = dist.sample(&mut rand);
+        assert_eq!(3, sample.len());
+        assert!((sample.iter().sum::<f64>() - 1.0).abs() < 1e-9, "sampled {sample:?}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "no concentration parameters")]
+fn dirichlet_empty() {
+    DirichletVec::new(vec![]);
+}
+
+#[test]
+#[should_panic(expected = "shape (0) must be greater than 0")]
+fn dirichlet_zero_alpha() {
+    DirichletVec::new(vec![1.0, 0.0]);
+}
+
+#[test]
+fn multinomial_sums_to_n() {
+    let mut rand = Wyrand::default();
+    let dist = MultinomialVec::new(1_000, vec![1.0, 0.0, 3.0]);
+    assert_eq!(1_000, dist.n());
+    assert_eq!(&[1.0, 0.0, 3.0], dist.weights());
+    for _ in 0..100 {
+        let counts = dist.sample(&mut rand);
+        assert_eq!(3, counts.len());
+        assert_eq!(1_000, counts.iter().sum::<u64>(), "sampled {counts:?}");
+        assert_eq!(0, counts[1]);
+    }
+}
+
+#[test]
+#[should_panic(expected = "all weights are zero")]
+fn multinomial_empty() {
+    MultinomialVec::new(10, vec![]);
+}
+
+#[test]
+#[should_panic(expected = "weight (inf) must be finite")]
+fn multinomial_infinite_weight() {
+    MultinomialVec::new(10, vec![f64::INFINITY]);
+}
+
+#[test]
+fn points_on_unit_hypersphere() {
+    let mut rand = Wyrand::default();
+    let dist = UnitHypersphereVec::new(4);
+    assert_eq!(4, dist.dim());
+    for _ in 0..100 {
+        let point = dist.sample(&mut rand);
+        assert_eq!(4, point.len());
+        assert!((norm_sq(&point) - 1.0).abs() < 1e-9, "sampled {point:?}");
+    }
+}
+
+#[test]
+fn points_inside_unit_ball() {
+    let mut rand = Wyrand::default();
+    let dist = UnitBallVec::new(4);
+    assert_eq!(4, dist.dim());
+    for _ in 0..100 {
+        let point = dist.sample(&mut rand);
+        assert_eq!(4, point.len());
+        assert!(norm_sq(&point) <= 1.0, "sampled {point:?}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "zero dimensions")]
+fn unit_hypersphere_zero_dimensions() {
+    UnitHypersphereVec::new(0);
+}
+
+#[test]
+#[should_panic(expected = "zero dimensions")]
+fn unit_ball_zero_dimensions() {
+    UnitBallVec::new(0);
+}
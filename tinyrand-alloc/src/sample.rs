@@ -0,0 +1,246 @@
+//! Sampling without replacement.
+
+use crate::weighted::{Weight, WeightError};
+use alloc::collections::{BTreeSet, BinaryHeap};
+use alloc::vec::Vec;
+use core::cmp::{Ordering, Reverse};
+use tinyrand::reservoir::algorithm_l;
+use tinyrand::Rand;
+
+/// The largest number of indices for which Floyd's algorithm is used. Its cost is quadratic in the
+/// number of indices (albeit with a tiny constant), so it is only used for small selections.
+const FLOYD_MAX_K: usize = 32;
+
+/// Random selection of multiple elements, without replacement.
+///
+/// This trait is implemented for all [`Rand`] types, so its methods may be invoked on any RNG.
+pub trait RandSample {
+    /// Chooses `k` distinct indices in `0..n` uniformly at random, returning them in random order.
+    ///
+    /// If `k` exceeds `n`, all `n` indices are returned (in random order). The algorithm is picked
+    /// based on the density of the selection:
+    ///
+    /// * if `k` is at least a quarter of `n`, a partial Fisher–Yates shuffle of `0..n`, taking
+    ///   O(_n_) time and space;
+    /// * otherwise, if `k` is small, R. Floyd's algorithm, taking O(_k_<sup>2</sup>) time and O(_k_) space;
+    /// * otherwise, rejection sampling (redrawing duplicates), taking O(_k_ log _k_) expected
+    ///   time and O(_k_) space.
+    ///
+    /// # Examples
+    /// ```
+    /// use tinyrand::StdRand;
+    /// use tinyrand_alloc::RandSample;
+    /// let mut rand = StdRand::default();
+    /// let indices = rand.sample_indices(1_000_000, 3);
+    /// assert_eq!(3, indices.len());
+    /// ```
+    fn sample_indices(&mut self, n: usize, k: usize) -> Vec<usize>;
+
+    /// Chooses `k` distinct elements of the slice uniformly at random, returning references to them
+    /// in random order. If `k` exceeds the length of the slice, all of its elements are returned
+    /// (in random order).
+    ///
+    /// # Examples
+    /// ```
+    /// use tinyrand::StdRand;
+    /// use tinyrand_alloc::RandSample;
+    /// let mut rand = StdRand::default();
+    /// let replicas = ["a", "b", "c", "d", "e"];
+    /// let chosen = rand.choose_multiple(&replicas, 2);
+    /// assert_eq!(2, chosen.len());
+    /// assert_ne!(chosen[0], chosen[1]);
+    /// ```
+    fn choose_multiple<'a, T>(&mut self, slice: &'a [T], k: usize) -> Vec<&'a T>;
+
+    /// Chooses `k` items from the iterator uniformly at random, without replacement, using
+    /// reservoir sampling. The iterator is consumed without buffering more than `k` items. If the
+    /// iterator yields fewer than `k` items, all of them are returned.
+    ///
+    /// The returned items are not in random order. (See [`tinyrand::RandIter`] for the
+    /// non-allocating variant.)
+    ///
+    /// # Examples
+    /// ```
+    /// use tinyrand::StdRand;
+    /// use tinyrand_alloc::RandSample;
+    /// let mut rand = StdRand::default();
+    /// let lines = rand.sample_iter("alpha\nbravo\ncharlie\ndelta".lines(), 2);
+    /// assert_eq!(2, lines.len());
+    /// ```
+    fn sample_iter<I: IntoIterator>(&mut self, iter: I, k: usize) -> Vec<I::Item>;
+
+    /// Chooses `k` distinct indices of the given weights, without replacement, such that each
+    /// successive index is chosen with a probability proportional to its weight among the indices
+    /// not yet chosen. The indices are returned in selection order.
+    ///
+    /// Indices with a zero weight are never chosen; if fewer than `k` weights are positive, only
+    /// the indices of the positive weights are returned. This uses the same algorithm as
+    /// [`RandSample::sample_weighted_iter`].
+    ///
+    /// # Errors
+    /// [`WeightError`] if any of the weights is negative, NaN or infinite.
+    ///
+    /// # Examples
+    /// ```
+    /// use tinyrand::StdRand;
+    /// use tinyrand_alloc::RandSample;
+    /// let mut rand = StdRand::default();
+    /// let capacities = [16u32, 32, 8, 64];
+    /// let canaries = rand.sample_weighted(&capacities, 2).unwrap();
+    /// assert_eq!(2, canaries.len());
+    /// assert_ne!(canaries[0], canaries[1]);
+    /// ```
+    fn sample_weighted<W: Weight>(&mut self, weights: &[W], k: usize) -> Result<Vec<usize>, WeightError>;
+
+    /// Chooses `k` distinct indices from a stream of weights, without replacement, such that each
+    /// successive index is chosen with a probability proportional to its weight among the indices
+    /// not yet chosen. The indices are returned in selection order.
+    ///
+    /// This is the A-Res algorithm of P. Efraimidis and P. Spirakis: each weight _w_ is assigned
+    /// the key ln(_U_)/_w_, where _U_ is uniform, and the `k` largest keys are retained in a heap.
+    /// The stream is consumed in O(_n_ log _k_) time, holding O(_k_) items in memory.
+    ///
+    /// Indices with a zero weight are never chosen; if fewer than `k` weights are positive, only
+    /// the indices of the positive weights are returned.
+    ///
+    /// # Errors
+    /// [`WeightError`] if any of the weights is negative, NaN or infinite.
+    fn sample_weighted_iter<W: Weight, I: IntoIterator<Item = W>>(
+        &mut self,
+        weights: I,
+        k: usize,
+    ) -> Result<Vec<usize>, WeightError>;
+}
+
+impl<R: Rand> RandSample for R {
+    fn sample_indices(&mut self, n: usize, k: usize) -> Vec<usize> {
+        let k = k.min(n);
+        if k >= n / 4 {
+            partial_fisher_yates(self, n, k)
+        } else if k <= FLOYD_MAX_K {
+            floyd(self, n, k)
+        } else {
+            rejection(self, n, k)
+        }
+    }
+
+    fn choose_multiple<'a, T>(&mut self, slice: &'a [T], k: usize) -> Vec<&'a T> {
+        self.sample_indices(slice.len(), k)
+            .into_iter()
+            .map(|index| &slice[index])
+            .collect()
+    }
+
+    fn sample_iter<I: IntoIterator>(&mut self, iter: I, k: usize) -> Vec<I::Item> {
+        let mut reservoir = Vec::new();
+        algorithm_l(self, iter, k, |index, item| {
+            if index == reservoir.len() {
+                reservoir.push(item);
+            } else {
+                reservoir[index] = item;
+            }
+        });
+        reservoir
+    }
+
+    fn sample_weighted<W: Weight>(&mut self, weights: &[W], k: usize) -> Result<Vec<usize>, WeightError> {
+        self.sample_weighted_iter(weights.iter().copied(), k)
+    }
+
+    fn sample_weighted_iter<W: Weight, I: IntoIterator<Item = W>>(
+        &mut self,
+        weights: I,
+        k: usize,
+    ) -> Result<Vec<usize>, WeightError> {
+        let mut heap = BinaryHeap::with_capacity(k);
+        for (index, weight) in weights.into_iter().enumerate() {
+            let weight = weight.to_f64();
+            if weight.is_nan() {
+                return Err(WeightError::NotANumber { index });
+            } else if weight < 0.0 {
+                return Err(WeightError::Negative { index });
+            } else if weight.is_infinite() {
+                return Err(WeightError::Infinite { index });
+            } else if weight == 0.0 || k == 0 {
+                continue;
+            }
+
+            // 1 - U is in (0, 1], so its logarithm is finite and non-positive
+            let key = Key(libm::log(1.0 - self.next_f64()) / weight, index);
+            if heap.len() < k {
+                heap.push(Reverse(key));
+            } else if let Some(mut smallest) = heap.peek_mut() {
+                if key > smallest.0 {
+                    *smallest = Reverse(key);
+                }
+            }
+        }
+        Ok(heap.into_sorted_vec().into_iter().map(|Reverse(Key(_, index))| index).collect())
+    }
+}
+
+/// A key of the Efraimidis–Spirakis algorithm, along with the index it was assigned to.
+/// Keys are ordered by their value, breaking ties by their index.
+struct Key(f64, usize);
+
+impl PartialEq for Key {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Key {}
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Key {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0).then(self.1.cmp(&other.1))
+    }
+}
+
+/// Shuffles the first `k` elements of `0..n` into place.
+pub(crate) fn partial_fisher_yates(rand: &mut impl Rand, n: usize, k: usize) -> Vec<usize> {
+    let mut indices = (0..n).collect::<Vec<_>>();
+    for i in 0..k.min(n.saturating_sub(1)) {
+        let j = i + rand.next_lim_usize(n - i);
+        indices.swap(i, j);
+    }
+    indices.truncate(k);
+    indices
+}
+
+/// Floyd's algorithm, in the variant that yields the indices in random order: when the drawn
+/// index has already been chosen, the new index `j` takes its place, and the drawn index is appended.
+pub(crate) fn floyd(rand: &mut impl Rand, n: usize, k: usize) -> Vec<usize> {
+    let mut indices = Vec::with_capacity(k);
+    for j in n - k..n {
+        let t = rand.next_lim_usize(j + 1);
+        if let Some(position) = indices.iter().position(|&index| index == t) {
+            indices[position] = j;
+        }
+        indices.push(t);
+    }
+    indices
+}
+
+/// Draws indices until `k` distinct ones have been found. Intended for sparse selections,
+/// where duplicates are rare.
+pub(crate) fn rejection(rand: &mut impl Rand, n: usize, k: usize) -> Vec<usize> {
+    let mut indices = Vec::with_capacity(k);
+    let mut chosen = BTreeSet::new();
+    while indices.len() < k {
+        let index = rand.next_lim_usize(n);
+        if chosen.insert(index) {
+            indices.push(index);
+        }
+    }
+    indices
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,208 @@
+use crate::sample::{floyd, partial_fisher_yates, rejection};
+use crate::{Mock, RandSample, WeightError};
+use alloc::vec;
+use alloc::vec::Vec;
+use tinyrand::{Rand, Wyrand};
+
+type Method = fn(&mut Wyrand, usize, usize) -> Vec<usize>;
+
+const METHODS: [(&str, Method); 3] = [
+    ("partial_fisher_yates", partial_fisher_yates),
+    ("floyd", floyd),
+    ("rejection", rejection),
+];
+
+fn assert_distinct(indices: &[usize], n: usize) {
+    let mut seen = vec![false; n];
+    for &index in indices {
+        assert!(index < n, "{index} out of range");
+        assert!(!seen[index], "{index} repeated");
+        seen[index] = true;
+    }
+}
+
+#[test]
+fn every_method_yields_distinct_indices() {
+    let mut rand = Wyrand::default();
+    for (name, method) in METHODS {
+        for (n, k) in [(1, 1), (10, 0), (10, 1), (10, 5), (10, 10), (1_000, 40)] {
+            let indices = method(&mut rand, n, k);
+            assert_eq!(k, indices.len(), "{name} for n={n}, k={k}");
+            assert_distinct(&indices, n);
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn every_method_is_uniform() {
+    // every ordered pair of distinct indices in 0..4 should be roughly equally likely
+    const N: usize = 4;
+    const ITERS: usize = 24_000;
+    for (name, method) in METHODS {
+        let mut rand = Wyrand::default();
+        let mut counts = [[0usize; N]; N];
+        for _ in 0..ITERS {
+            let indices = method(&mut rand, N, 2);
+            counts[indices[0]][indices[1]] += 1;
+        }
+        let expected = ITERS as f64 / 12.0;
+        for (first, row) in counts.iter().enumerate() {
+            for (second, &count) in row.iter().enumerate() {
+                if first == second {
+                    assert_eq!(0, count);
+                } else {
+                    let deviation = (count as f64 - expected).abs() / expected;
+                    assert!(deviation < 0.1, "{name} for ({first}, {second}): {count} vs {expected}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn sample_indices_clamps_k() {
+    let mut rand = Wyrand::default();
+    for n in [0, 1, 5, 100] {
+        let mut indices = rand.sample_indices(n, n + 10);
+        assert_eq!(n, indices.len());
+        indices.sort_unstable();
+        assert_eq!((0..n).collect::<Vec<_>>(), indices);
+    }
+}
+
+#[test]
+fn sample_indices_sparse() {
+    let mut rand = Wyrand::default();
+    for k in [1, 32, 33, 1_000] {
+        let indices = rand.sample_indices(usize::MAX, k);
+        assert_eq!(k, indices.len());
+        let mut sorted = indices.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(k, sorted.len());
+    }
+}
+
+#[test]
+fn sample_indices_draws_with_next_lim() {
+    // a dense selection via partial Fisher–Yates, where each draw swaps the last index into the prefix
+    let mut rand = Mock::default().with_next_lim_u128(|_, lim| lim - 1);
+    assert_eq!(vec![4, 0, 1], rand.sample_indices(5, 3));
+    assert_eq!(3, rand.state().next_lim_u128_invocations());
+}
+
+#[test]
+fn choose_multiple_from_slice() {
+    let mut rand = Wyrand::default();
+    let slice = ["a", "b", "c", "d", "e"];
+    let chosen = rand.choose_multiple(&slice, 3);
+    assert_eq!(3, chosen.len());
+    for element in &chosen {
+        assert!(slice.contains(element));
+    }
+    assert!(rand.choose_multiple::<u8>(&[], 3).is_empty());
+    assert_eq!(5, rand.choose_multiple(&slice, 10).len());
+}
+
+#[test]
+fn choose_multiple_with_mock() {
+    let mut rand = Mock::default().with_next_lim_u128(|_, _| 0);
+    assert_eq!(vec![&"a", &"b"], rand.choose_multiple(&["a", "b", "c"], 2));
+    assert_eq!(0, rand.next_lim_usize(1));
+}
+
+#[test]
+fn sample_iter_yields_distinct_items() {
+    let mut rand = Wyrand::default();
+    for (n, k) in [(0, 3), (2, 3), (3, 3), (100, 0), (100, 10), (1_000, 1)] {
+        let mut items = rand.sample_iter(0..n, k);
+        assert_eq!(k.min(n), items.len(), "for n={n}, k={k}");
+        items.sort_unstable();
+        items.dedup();
+        assert_eq!(k.min(n), items.len(), "for n={n}, k={k}");
+    }
+}
+
+#[test]
+fn sample_weighted_yields_distinct_indices() {
+    let mut rand = Wyrand::default();
+    let weights = [3u32, 1, 4, 1, 5, 9, 2, 6];
+    for k in 0..=weights.len() {
+        let indices = rand.sample_weighted(&weights, k).unwrap();
+        assert_eq!(k, indices.len());
+        assert_distinct(&indices, weights.len());
+    }
+}
+
+#[test]
+fn sample_weighted_skips_zero_weights() {
+    let mut rand = Wyrand::default();
+    for _ in 0..100 {
+        let mut indices = rand.sample_weighted(&[0.0, 2.5, 0.0, 0.5, 0.0], 5).unwrap();
+        indices.sort_unstable();
+        assert_eq!(vec![1, 3], indices);
+    }
+    assert!(rand.sample_weighted::<u64>(&[], 3).unwrap().is_empty());
+    assert!(rand.sample_weighted(&[0u64, 0], 1).unwrap().is_empty());
+}
+
+#[test]
+fn sample_weighted_rejects_invalid_weights() {
+    let mut rand = Wyrand::default();
+    assert_eq!(
+        Err(WeightError::Negative { index: 1 }),
+        rand.sample_weighted(&[1.0, -1.0], 1)
+    );
+    assert_eq!(
+        Err(WeightError::NotANumber { index: 2 }),
+        rand.sample_weighted(&[1.0, 0.0, f64::NAN], 1)
+    );
+    assert_eq!(
+        Err(WeightError::Infinite { index: 0 }),
+        rand.sample_weighted_iter([f64::INFINITY], 0)
+    );
+}
+
+#[test]
+fn sample_weighted_prefers_largest_key() {
+    // the first draw is the highest possible, giving the smallest key; the rest tie on the
+    // largest key, which is broken by the index
+    let mut draws = 0;
+    let mut rand = Mock::default().with_next_u128(move |_| {
+        draws += 1;
+        if draws == 1 {
+            u128::MAX
+        } else {
+            0
+        }
+    });
+    assert_eq!(vec![2, 1], rand.sample_weighted(&[1u32, 1, 1], 2).unwrap());
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn sample_weighted_in_selection_order() {
+    // each ordered pair should occur with probability w_i / W × w_j / (W - w_i)
+    const ITERS: usize = 100_000;
+    let weights = [1u64, 2, 3, 4];
+    let total = weights.iter().sum::<u64>() as f64;
+    let mut rand = Wyrand::default();
+    let mut counts = [[0usize; 4]; 4];
+    for _ in 0..ITERS {
+        let indices = rand.sample_weighted_iter(weights, 2).unwrap();
+        counts[indices[0]][indices[1]] += 1;
+    }
+    for (first, row) in counts.iter().enumerate() {
+        for (second, &count) in row.iter().enumerate() {
+            if first == second {
+                assert_eq!(0, count);
+            } else {
+                let (w_first, w_second) = (weights[first] as f64, weights[second] as f64);
+                let expected = ITERS as f64 * w_first / total * w_second / (total - w_first);
+                let deviation = (count as f64 - expected).abs() / expected;
+                assert!(deviation < 0.1, "({first}, {second}): {count} vs {expected}");
+            }
+        }
+    }
+}
@@ -0,0 +1,365 @@
+//! Weighted random selection.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use tinyrand::{Distribution, Rand};
+
+/// The reasons a set of weights may be rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeightError {
+    /// No weights were given.
+    Empty,
+
+    /// The weight at the given index is negative.
+    Negative { index: usize },
+
+    /// The weight at the given index is NaN.
+    NotANumber { index: usize },
+
+    /// The weight at the given index is infinite.
+    Infinite { index: usize },
+
+    /// All weights are zero, so no index can be selected.
+    AllZero,
+
+    /// The sum of the weights exceeds the range of the weight type.
+    Overflow,
+}
+
+impl Display for WeightError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no weights"),
+            Self::Negative { index } => write!(f, "weight at index {index} cannot be less than 0"),
+            Self::NotANumber { index } => write!(f, "weight at index {index} is NaN"),
+            Self::Infinite { index } => write!(f, "weight at index {index} must be finite"),
+            Self::AllZero => write!(f, "all weights are zero"),
+            Self::Overflow => write!(f, "total weight overflows"),
+        }
+    }
+}
+
+/// A type that can be used as a weight.
+pub trait Weight: Copy {
+    /// Converts the weight to an `f64`, which may be lossy for very large integers.
+    fn to_f64(self) -> f64;
+}
+
+impl Weight for u32 {
+    #[inline(always)]
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+}
+
+impl Weight for u64 {
+    #[inline(always)]
+    #[allow(clippy::cast_precision_loss)]
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl Weight for f64 {
+    #[inline(always)]
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+/// Selects indices with probabilities proportional to their weights, using
+/// [Walker's alias method](https://en.wikipedia.org/wiki/Alias_method) (in the variant of M. Vose).
+///
+/// Construction takes O(_n_) time. Sampling takes O(1) time, irrespective of the number of
+/// weights: a single call to [`Rand::next_lim_usize`] selects both a column of the alias table
+/// and a position within it, which is compared with the column's threshold.
+///
+/// The probabilities are quantised to a resolution of 2<sup>-_b_</sup> per column, where _b_ is the
+/// number of leading zero bits in the number of weights (e.g., 54 bits for 1,000 weights on a 64-bit
+/// platform), and further limited by the 53-bit precision of `f64`.
+///
+/// # Examples
+/// ```
+/// use tinyrand::{Distribution, StdRand};
+/// use tinyrand_alloc::WeightedAlias;
+/// let backends = ["a", "b", "c"];
+/// let dist = WeightedAlias::new(&[70u32, 20, 10]).unwrap();
+/// let mut rand = StdRand::default();
+/// let backend = backends[dist.sample(&mut rand)];
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeightedAlias {
+    thresholds: Vec<usize>,
+    aliases: Vec<usize>,
+    shift: u32,
+}
+
+impl WeightedAlias {
+    /// Builds an alias table from the given weights, which need not sum to 1.
+    ///
+    /// # Errors
+    /// [`WeightError`] if `weights` is empty, if any of the weights is negative, NaN or infinite, or
+    /// if all weights are zero.
+    pub fn new<W: Weight>(weights: &[W]) -> Result<Self, WeightError> {
+        if weights.is_empty() {
+            return Err(WeightError::Empty);
+        }
+        let (mut max, mut heaviest) = (0f64, 0);
+        for (index, weight) in weights.iter().enumerate() {
+            let weight = weight.to_f64();
+            if weight.is_nan() {
+                return Err(WeightError::NotANumber { index });
+            } else if weight < 0.0 {
+                return Err(WeightError::Negative { index });
+            } else if weight.is_infinite() {
+                return Err(WeightError::Infinite { index });
+            }
+            if weight > max {
+                max = weight;
+                heaviest = index;
+            }
+        }
+        if max == 0.0 {
+            return Err(WeightError::AllZero);
+        }
+
+        // scale by the largest weight first, so that the sum cannot overflow
+        let n = weights.len();
+        let mut scaled = weights.iter().map(|weight| weight.to_f64() / max).collect::<Vec<_>>();
+        let sum = scaled.iter().sum::<f64>();
+        #[allow(clippy::cast_precision_loss)]
+        let factor = n as f64 / sum;
+        for weight in &mut scaled {
+            *weight *= factor;
+        }
+
+        let shift = n.leading_zeros();
+        let capacity = 1usize << shift;
+        #[allow(clippy::cast_precision_loss)]
+        let capacity_f = capacity as f64;
+        let mut thresholds = vec![capacity; n];
+        let mut aliases = (0..n).collect::<Vec<_>>();
+        let (mut small, mut large): (Vec<_>, Vec<_>) = (0..n).partition(|&index| scaled[index] < 1.0);
+        while let (Some(&underfull), Some(&overfull)) = (small.last(), large.last()) {
+            small.pop();
+            #[allow(clippy::cast_possible_truncation)]
+            #[allow(clippy::cast_sign_loss)]
+            let threshold = (scaled[underfull] * capacity_f) as usize;
+            thresholds[underfull] = threshold.min(capacity);
+            aliases[underfull] = overfull;
+            scaled[overfull] = (scaled[overfull] + scaled[underfull]) - 1.0;
+            if scaled[overfull] < 1.0 {
+                large.pop();
+                small.push(overfull);
+            }
+        }
+        // any columns left in either list are full, bar rounding errors; the errors must not
+        // make a zero weight selectable, however
+        for index in small {
+            if weights[index].to_f64() == 0.0 {
+                thresholds[index] = 0;
+                aliases[index] = heaviest;
+            }
+        }
+
+        Ok(Self {
+            thresholds,
+            aliases,
+            shift,
+        })
+    }
+
+    /// The number of weights (including zero weights).
+    pub fn len(&self) -> usize {
+        self.thresholds.len()
+    }
+
+    /// Always `false`, as an alias table cannot be built from an empty set of weights.
+    pub fn is_empty(&self) -> bool {
+        self.thresholds.is_empty()
+    }
+}
+
+impl Distribution<usize> for WeightedAlias {
+    #[inline(always)]
+    fn sample(&self, rand: &mut impl Rand) -> usize {
+        let random = rand.next_lim_usize(self.thresholds.len() << self.shift);
+        let column = random >> self.shift;
+        let position = random & ((1 << self.shift) - 1);
+        if position < self.thresholds[column] {
+            column
+        } else {
+            self.aliases[column]
+        }
+    }
+}
+
+/// Selects indices with probabilities proportional to their weights, where the weights may change
+/// over time.
+///
+/// The weights are held in a [Fenwick tree](https://en.wikipedia.org/wiki/Fenwick_tree), so that
+/// [`DynamicWeighted::push`], [`DynamicWeighted::update`] and sampling each take O(log _n_) time.
+/// Sampling draws a single random number in `0..total_weight` with [`Rand::next_lim_u64`] and
+/// searches the tree for the index it falls in. The weights are integers, so the selection is
+/// exact (free of rounding bias) and, for a given seed, reproducible across platforms.
+///
+/// A sample is `None` if the total weight is zero (including when there are no weights).
+///
+/// # Examples
+/// ```
+/// use tinyrand::{Distribution, StdRand};
+/// use tinyrand_alloc::DynamicWeighted;
+/// let mut dist = DynamicWeighted::from_weights(&[70, 20, 10]).unwrap();
+/// let mut rand = StdRand::default();
+/// assert!(dist.sample(&mut rand).unwrap() < 3);
+///
+/// // backend 0 is draining
+/// dist.update(0, 0).unwrap();
+/// assert_ne!(Some(0), dist.sample(&mut rand));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DynamicWeighted {
+    weights: Vec<u64>,
+    tree: Vec<u64>,
+    total_weight: u64,
+}
+
+impl DynamicWeighted {
+    /// Creates an empty [`DynamicWeighted`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a [`DynamicWeighted`] from the given weights in O(_n_) time.
+    ///
+    /// # Errors
+    /// [`WeightError::Overflow`] if the weights sum to more than `u64::MAX`.
+    pub fn from_weights(weights: &[u64]) -> Result<Self, WeightError> {
+        let mut total_weight = 0u64;
+        for &weight in weights {
+            total_weight = total_weight.checked_add(weight).ok_or(WeightError::Overflow)?;
+        }
+
+        // build the tree bottom-up, propagating each node's partial sum to its parent
+        let mut tree = weights.to_vec();
+        for index in 0..tree.len() {
+            let parent = index | (index + 1);
+            if parent < tree.len() {
+                tree[parent] += tree[index];
+            }
+        }
+        Ok(Self {
+            weights: weights.to_vec(),
+            tree,
+            total_weight,
+        })
+    }
+
+    /// Appends a weight, returning its index.
+    ///
+    /// # Errors
+    /// [`WeightError::Overflow`] if the total weight would exceed `u64::MAX`.
+    pub fn push(&mut self, weight: u64) -> Result<usize, WeightError> {
+        let total_weight = self.total_weight.checked_add(weight).ok_or(WeightError::Overflow)?;
+        let index = self.weights.len();
+        // the new node covers the range (index & (index + 1))..=index, of which all but
+        // the last element are already in the tree
+        let covered_start = index & (index + 1);
+        let node = weight + self.prefix_sum(index) - self.prefix_sum(covered_start);
+        self.weights.push(weight);
+        self.tree.push(node);
+        self.total_weight = total_weight;
+        Ok(index)
+    }
+
+    /// Changes the weight at the given index.
+    ///
+    /// # Errors
+    /// [`WeightError::Overflow`] if the total weight would exceed `u64::MAX`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn update(&mut self, index: usize, weight: u64) -> Result<(), WeightError> {
+        let old_weight = self.weights[index];
+        let total_weight = (self.total_weight - old_weight)
+            .checked_add(weight)
+            .ok_or(WeightError::Overflow)?;
+        let mut node = index;
+        while node < self.tree.len() {
+            // the node sums cannot overflow, as none exceeds the total
+            self.tree[node] = self.tree[node] - old_weight + weight;
+            node |= node + 1;
+        }
+        self.weights[index] = weight;
+        self.total_weight = total_weight;
+        Ok(())
+    }
+
+    /// The weight at the given index.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn weight(&self, index: usize) -> u64 {
+        self.weights[index]
+    }
+
+    /// The sum of the weights.
+    pub fn total_weight(&self) -> u64 {
+        self.total_weight
+    }
+
+    /// The number of weights (including zero weights).
+    pub fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// Checks whether there are no weights.
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+
+    /// The sum of the weights in `0..end`.
+    fn prefix_sum(&self, end: usize) -> u64 {
+        let mut sum = 0;
+        let mut end = end;
+        while end > 0 {
+            sum += self.tree[end - 1];
+            end &= end - 1;
+        }
+        sum
+    }
+
+    /// Finds the index whose cumulative weight range contains `point`, by descending the tree.
+    fn find(&self, point: u64) -> usize {
+        let mut remaining = point;
+        let mut index = 0;
+        // the largest power of two not exceeding the length of the tree
+        let mut step = match self.tree.len() {
+            0 => 0,
+            len => 1 << (usize::BITS - 1 - len.leading_zeros()),
+        };
+        while step > 0 {
+            let next = index + step;
+            if next <= self.tree.len() && self.tree[next - 1] <= remaining {
+                remaining -= self.tree[next - 1];
+                index = next;
+            }
+            step >>= 1;
+        }
+        index
+    }
+}
+
+impl Distribution<Option<usize>> for DynamicWeighted {
+    fn sample(&self, rand: &mut impl Rand) -> Option<usize> {
+        if self.total_weight == 0 {
+            None
+        } else {
+            Some(self.find(rand.next_lim_u64(self.total_weight)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,195 @@
+use crate::{DynamicWeighted, Mock, WeightError, WeightedAlias};
+use alloc::format;
+use alloc::vec::Vec;
+use tinyrand::{Distribution, Wyrand};
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn frequencies_match_weights() {
+    let weights = [1u32, 0, 2, 7, 0, 10];
+    let dist = WeightedAlias::new(&weights).unwrap();
+    assert_eq!(6, dist.len());
+    assert!(!dist.is_empty());
+    let mut rand = Wyrand::default();
+    const N: usize = 200_000;
+    let mut counts = [0usize; 6];
+    for _ in 0..N {
+        counts[dist.sample(&mut rand)] += 1;
+    }
+    for (&count, &weight) in counts.iter().zip(&weights) {
+        let expected = N as f64 * f64::from(weight) / 20.0;
+        if weight == 0 {
+            assert_eq!(0, count);
+        } else {
+            assert!((count as f64 - expected).abs() < 0.03 * expected, "{count} vs {expected}");
+        }
+    }
+}
+
+#[test]
+fn float_and_integer_weights_agree() {
+    assert_eq!(
+        WeightedAlias::new(&[1u64, 2, 3, 4]).unwrap(),
+        WeightedAlias::new(&[0.1, 0.2, 0.3, 0.4]).unwrap()
+    );
+    assert_eq!(
+        WeightedAlias::new(&[1u32, 2, 3, 4]).unwrap(),
+        WeightedAlias::new(&[1u64, 2, 3, 4]).unwrap()
+    );
+}
+
+#[test]
+fn single_weight() {
+    let dist = WeightedAlias::new(&[0.5]).unwrap();
+    let mut rand = Wyrand::default();
+    for _ in 0..10 {
+        assert_eq!(0, dist.sample(&mut rand));
+    }
+}
+
+#[test]
+fn huge_weights() {
+    let dist = WeightedAlias::new(&[f64::MAX, f64::MAX, 0.0]).unwrap();
+    let mut rand = Wyrand::default();
+    for _ in 0..100 {
+        assert!(dist.sample(&mut rand) < 2);
+    }
+    WeightedAlias::new(&[u64::MAX; 3]).unwrap();
+}
+
+#[test]
+fn sample_makes_one_draw() {
+    // two columns of equal weight; the draw selects the column in its upper bits
+    let dist = WeightedAlias::new(&[1u32, 1]).unwrap();
+    let mut rand = Mock::default().with_next_lim_u128(|_, lim| lim - 1);
+    assert_eq!(1, dist.sample(&mut rand));
+    let mut rand = Mock::default().with_next_lim_u128(|_, _| 0);
+    assert_eq!(0, dist.sample(&mut rand));
+    assert_eq!(1, rand.state().next_lim_u128_invocations());
+}
+
+#[test]
+fn underfull_column_uses_alias() {
+    // with two columns, column 0 holds half of its capacity; the rest is aliased to column 1
+    let dist = WeightedAlias::new(&[1u32, 3]).unwrap();
+    let capacity = 1u128 << (2usize.leading_zeros());
+    let mut rand = Mock::default().with_next_lim_u128(move |_, _| capacity / 2 - 1);
+    assert_eq!(0, dist.sample(&mut rand));
+    let mut rand = Mock::default().with_next_lim_u128(move |_, _| capacity / 2);
+    assert_eq!(1, dist.sample(&mut rand));
+}
+
+#[test]
+fn invalid_weights() {
+    assert_eq!(Err(WeightError::Empty), WeightedAlias::new::<u32>(&[]));
+    assert_eq!(Err(WeightError::AllZero), WeightedAlias::new(&[0u32, 0]));
+    assert_eq!(Err(WeightError::AllZero), WeightedAlias::new(&[0.0, -0.0]));
+    assert_eq!(Err(WeightError::Negative { index: 1 }), WeightedAlias::new(&[1.0, -1.0]));
+    assert_eq!(Err(WeightError::NotANumber { index: 0 }), WeightedAlias::new(&[f64::NAN]));
+    assert_eq!(Err(WeightError::Infinite { index: 2 }), WeightedAlias::new(&[1.0, 1.0, f64::INFINITY]));
+}
+
+#[test]
+fn error_display() {
+    assert_eq!("no weights", format!("{}", WeightError::Empty));
+    assert_eq!("weight at index 3 cannot be less than 0", format!("{}", WeightError::Negative { index: 3 }));
+    assert_eq!("weight at index 0 is NaN", format!("{}", WeightError::NotANumber { index: 0 }));
+    assert_eq!("weight at index 1 must be finite", format!("{}", WeightError::Infinite { index: 1 }));
+    assert_eq!("all weights are zero", format!("{}", WeightError::AllZero));
+    assert_eq!("total weight overflows", format!("{}", WeightError::Overflow));
+}
+
+#[test]
+fn dynamic_empty() {
+    let dist = DynamicWeighted::new();
+    assert!(dist.is_empty());
+    assert_eq!(0, dist.total_weight());
+    assert_eq!(None, dist.sample(&mut Mock::default()));
+}
+
+#[test]
+fn dynamic_all_zero() {
+    let mut dist = DynamicWeighted::from_weights(&[0, 0]).unwrap();
+    assert_eq!(None, dist.sample(&mut Mock::default()));
+    dist.update(1, 5).unwrap();
+    let mut rand = Wyrand::default();
+    for _ in 0..10 {
+        assert_eq!(Some(1), dist.sample(&mut rand));
+    }
+}
+
+#[test]
+fn dynamic_selects_every_point_exactly() {
+    // every point in 0..total_weight maps to the index whose cumulative range contains it
+    let weights = [3u64, 0, 1, 4, 0, 0, 2, 5, 1];
+    let dist = DynamicWeighted::from_weights(&weights).unwrap();
+    assert_eq!(16, dist.total_weight());
+    let mut expected = Vec::new();
+    for (index, &weight) in weights.iter().enumerate() {
+        for _ in 0..weight {
+            expected.push(index);
+        }
+    }
+    for (point, &index) in expected.iter().enumerate() {
+        let mut rand = Mock::default().with_next_lim_u128(move |_, lim| {
+            assert_eq!(16, lim);
+            point as u128
+        });
+        assert_eq!(Some(index), dist.sample(&mut rand), "point {point}");
+    }
+}
+
+#[test]
+fn dynamic_push_matches_from_weights() {
+    let weights = (0..100u64).map(|i| i * 7 % 13).collect::<Vec<_>>();
+    let mut pushed = DynamicWeighted::new();
+    for (index, &weight) in weights.iter().enumerate() {
+        assert_eq!(index, pushed.push(weight).unwrap());
+    }
+    assert_eq!(DynamicWeighted::from_weights(&weights).unwrap(), pushed);
+}
+
+#[test]
+fn dynamic_update() {
+    let mut dist = DynamicWeighted::from_weights(&[1, 2, 3, 4, 5]).unwrap();
+    dist.update(2, 10).unwrap();
+    dist.update(0, 0).unwrap();
+    assert_eq!(10, dist.weight(2));
+    assert_eq!(21, dist.total_weight());
+    assert_eq!(DynamicWeighted::from_weights(&[0, 2, 10, 4, 5]).unwrap(), dist);
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn dynamic_frequencies_match_weights() {
+    let mut dist = DynamicWeighted::from_weights(&[5, 5, 5]).unwrap();
+    dist.update(0, 10).unwrap();
+    dist.push(0).unwrap();
+    let mut rand = Wyrand::default();
+    const N: usize = 100_000;
+    let mut counts = [0usize; 4];
+    for _ in 0..N {
+        counts[dist.sample(&mut rand).unwrap()] += 1;
+    }
+    for (&count, weight) in counts.iter().zip([10.0, 5.0, 5.0, 0.0]) {
+        let expected = N as f64 * weight / 20.0;
+        assert!((count as f64 - expected).abs() <= 0.03 * expected, "{count} vs {expected}");
+    }
+}
+
+#[test]
+fn dynamic_overflow() {
+    assert_eq!(Err(WeightError::Overflow), DynamicWeighted::from_weights(&[u64::MAX, 1]));
+    let mut dist = DynamicWeighted::from_weights(&[u64::MAX - 1, 0]).unwrap();
+    assert_eq!(Err(WeightError::Overflow), dist.push(2));
+    assert_eq!(Err(WeightError::Overflow), dist.update(1, 2));
+    dist.update(1, 1).unwrap();
+    assert_eq!(u64::MAX, dist.total_weight());
+    assert_eq!(DynamicWeighted::from_weights(&[u64::MAX - 1, 1]).unwrap(), dist);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn dynamic_update_out_of_bounds() {
+    DynamicWeighted::new().update(0, 1).unwrap();
+}
@@ -47,7 +47,7 @@ fn next_lim() {
 /// Generate a number in the given range.
 fn next_range() {
     let mut rand = StdRand::default();
-    let tasks = vec!["went to market", "stayed home", "had roast beef", "had none"];
+    let tasks = ["went to market", "stayed home", "had roast beef", "had none"];
     let random_index = rand.next_range(0..tasks.len());
     let random_task = tasks[random_index];
     println!("This little piggy {random_task}");
@@ -97,10 +97,10 @@ fn with_bool_from_state() {
     let mut rand = Mock::default().with_next_bool(|surrogate, _| {
         surrogate.state().next_bool_invocations() % 2 == 0
     });
-    assert_eq!(true, rand.next_bool(Probability::new(0.5)));
-    assert_eq!(false, rand.next_bool(Probability::new(0.5)));
-    assert_eq!(true, rand.next_bool(Probability::new(0.5)));
-    assert_eq!(false, rand.next_bool(Probability::new(0.5)));
+    assert!(rand.next_bool(Probability::new(0.5)));
+    assert!(!rand.next_bool(Probability::new(0.5)));
+    assert!(rand.next_bool(Probability::new(0.5)));
+    assert!(!rand.next_bool(Probability::new(0.5)));
 }
 
 fn with_lim() {
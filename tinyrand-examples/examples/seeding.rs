@@ -1,7 +1,7 @@
 //! How to seed a [`Rand`].
 
-use tinyrand::{Rand, Seeded, StdRand};
-use tinyrand_std::{ClockSeed, thread_rand};
+use tinyrand::{Rand, SeedSource, Seeded, StdRand};
+use tinyrand_std::{ClockSeed, EnvSeed, OsSeed, thread_rand};
 
 #[test]
 fn run_main() {
@@ -11,6 +11,8 @@ fn run_main() {
 fn main() {
     seed_from_u64();
     seed_from_clock();
+    seed_from_os();
+    seed_from_env();
     thread_local();
 }
 
@@ -23,13 +25,30 @@ fn seed_from_u64() {
 
 /// Seed from the system clock. Requires `tinyrand-std`.
 fn seed_from_clock() {
-    let seed = ClockSeed::default().next_u64();
+    let seed = ClockSeed.next_seed().unwrap();
     println!("seeding with {seed}");
     let mut rand = StdRand::seed(seed);
     let num = rand.next_u64();
     println!("generated {num}");
 }
 
+/// Seed from the operating system's entropy source. Requires `tinyrand-std`.
+fn seed_from_os() {
+    let mut rand = StdRand::from_source(&mut OsSeed).expect("no entropy source");
+    let num = rand.next_u64();
+    println!("generated {num}");
+}
+
+/// Seed from an environment variable if it is set, otherwise from the operating system. Requires
+/// `tinyrand-std`.
+fn seed_from_env() {
+    let mut rand = StdRand::from_source(&mut EnvSeed::new("SEED"))
+        .or_else(|_| StdRand::from_source(&mut OsSeed))
+        .unwrap();
+    let num = rand.next_u64();
+    println!("generated {num}");
+}
+
 /// A thread-local RNG instance.
 fn thread_local() {
     let mut rand = thread_rand();
@@ -0,0 +1,98 @@
+//! Exercises the `#[tinyrand::test]` attribute.
+
+use std::cell::{Cell, RefCell};
+use tinyrand::{Rand, SeedSequence, Seeded, StdRand};
+
+thread_local! {
+    /// The first number drawn in each run of a test, on the calling thread.
+    static DRAWS: RefCell<Vec<u64>> = const { RefCell::new(Vec::new()) };
+
+    /// Whether [`fails_when_armed`] should fail, on the calling thread.
+    static ARMED: Cell<bool> = const { Cell::new(false) };
+}
+
+fn take_draws() -> Vec<u64> {
+    DRAWS.with(|draws| draws.take())
+}
+
+#[tinyrand::test(iterations = 10)]
+fn records_draws(rand: &mut StdRand) {
+    DRAWS.with(|draws| draws.borrow_mut().push(rand.next_u64()));
+}
+
+#[tinyrand::test(seed = 42)]
+fn records_replay(rand: &mut StdRand) {
+    DRAWS.with(|draws| draws.borrow_mut().push(rand.next_u64()));
+}
+
+#[tinyrand::test]
+fn records_default(rand: &mut StdRand) {
+    DRAWS.with(|draws| draws.borrow_mut().push(rand.next_u64()));
+}
+
+#[test]
+fn iterations_seeded_by_path() {
+    take_draws();
+    records_draws();
+    let path = concat!(module_path!(), "::records_draws");
+    let expected: Vec<_> = (0..10)
+        .map(|iteration| StdRand::seed(SeedSequence::new(0).with_str(path).child(iteration).seed()).next_u64())
+        .collect();
+    assert_eq!(expected, take_draws());
+
+    // the same seeds on every run
+    records_draws();
+    assert_eq!(expected, take_draws());
+}
+
+#[test]
+fn seed_replays_once() {
+    take_draws();
+    records_replay();
+    assert_eq!(vec![StdRand::seed(42).next_u64()], take_draws());
+}
+
+#[test]
+fn default_iterations() {
+    take_draws();
+    records_default();
+    assert_eq!(100, take_draws().len());
+}
+
+#[tinyrand::test(iterations = 5)]
+fn returns_result(rand: &mut StdRand) -> Result<(), String> {
+    let lim = rand.next_lim_u64(1_000) + 1;
+    if rand.next_lim_u64(lim) < lim {
+        Ok(())
+    } else {
+        Err(format!("out of range for {lim}"))
+    }
+}
+
+#[tinyrand::test(iterations = 5)]
+#[allow(clippy::unused_unit)]
+fn returns_explicit_unit(rand: &mut StdRand) -> () {
+    let lim = rand.next_lim_u64(1_000) + 1;
+    assert!(rand.next_lim_u64(lim) < lim);
+}
+
+#[tinyrand::test(iterations = 5)]
+fn fails_when_armed(_rand: &mut StdRand) -> Result<(), String> {
+    if ARMED.with(Cell::get) {
+        Err("failed".into())
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+fn err_propagated() {
+    ARMED.with(|armed| armed.set(true));
+    assert_eq!(Err("failed".into()), fails_when_armed());
+}
+
+#[tinyrand::test(iterations = 5)]
+#[should_panic(expected = "too large")]
+fn panic_propagated(rand: &mut StdRand) {
+    assert!(rand.next_u64() < 1, "too large");
+}
@@ -0,0 +1,53 @@
+//! Procedural macros for `tinyrand`.
+//!
+//! These are re-exported by `tinyrand` when its `macros` feature is enabled, and are best used
+//! by way of that crate.
+
+mod test_attr;
+
+use proc_macro::TokenStream;
+
+/// Marks a function as a randomised test, which is run several times, each with a freshly seeded
+/// `StdRand`.
+///
+/// The function takes a single argument — the generator, as a `&mut StdRand` — and returns either
+/// `()` or a `Result<(), E>`, as does any other test. The `#[test]` attribute is added by the
+/// macro, and must not be repeated. Other attributes, such as `#[ignore]` and `#[should_panic]`,
+/// are kept.
+///
+/// # Options
+/// * `iterations = N` — the number of times to run the test (100 by default). The seed of
+///   iteration _i_ is that of `SeedSequence::new(0).with_str(path).child(i)`, where `path` is
+///   the test's path, as in `module_path!()::name`. The seeds are thus the same on every run,
+///   but differ between tests.
+/// * `seed = S` — runs the test once, with `StdRand::seed(S)`, ignoring `iterations`. Used to
+///   replay a failure.
+///
+/// Should an iteration fail, its number and seed are printed, along with the attribute that
+/// replays it. (Panics are not reported under `#[should_panic]`, where they are expected.)
+///
+/// The expanded test refers to `::tinyrand`, so the `tinyrand` dependency must not be renamed
+/// in `Cargo.toml`.
+///
+/// # Examples
+/// ```ignore
+/// use tinyrand::{Rand, StdRand};
+///
+/// #[tinyrand::test(iterations = 100)]
+/// fn lim_within_bounds(rand: &mut StdRand) {
+///     let lim = rand.next_lim_u64(1_000) + 1;
+///     assert!(rand.next_lim_u64(lim) < lim);
+/// }
+///
+/// #[tinyrand::test(seed = 42)]
+/// fn lim_within_bounds_replay(rand: &mut StdRand) {
+///     let lim = rand.next_lim_u64(1_000) + 1;
+///     assert!(rand.next_lim_u64(lim) < lim);
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn test(args: TokenStream, item: TokenStream) -> TokenStream {
+    test_attr::expand(args.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,171 @@
+//! Expansion of the [`test`](crate::test) attribute.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::Parser;
+use syn::{FnArg, ItemFn, LitInt, ReturnType, Type};
+
+/// The number of iterations, if not specified.
+const DEFAULT_ITERATIONS: u64 = 100;
+
+/// The options given to the attribute.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Args {
+    pub iterations: u64,
+    pub seed: Option<u64>,
+}
+
+impl Args {
+    pub fn parse(args: TokenStream) -> syn::Result<Self> {
+        let mut iterations = None;
+        let mut seed = None;
+        let parser = syn::meta::parser(|meta| {
+            if meta.path.is_ident("iterations") {
+                if iterations.is_some() {
+                    return Err(meta.error("duplicate option `iterations`"));
+                }
+                let lit: LitInt = meta.value()?.parse()?;
+                let value = lit.base10_parse()?;
+                if value == 0 {
+                    return Err(syn::Error::new_spanned(lit, "`iterations` must be positive"));
+                }
+                iterations = Some(value);
+                Ok(())
+            } else if meta.path.is_ident("seed") {
+                if seed.is_some() {
+                    return Err(meta.error("duplicate option `seed`"));
+                }
+                seed = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported option; expected `iterations` or `seed`"))
+            }
+        });
+        parser.parse2(args)?;
+        Ok(Self {
+            iterations: iterations.unwrap_or(DEFAULT_ITERATIONS),
+            seed,
+        })
+    }
+}
+
+pub fn expand(args: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let args = Args::parse(args)?;
+    let func: ItemFn = syn::parse2(item)?;
+    validate(&func)?;
+
+    let ItemFn { attrs, vis, sig, block } = &func;
+    let name = &sig.ident;
+    let inputs = &sig.inputs;
+    let output = &sig.output;
+    let iterations = args.iterations;
+
+    // the (optional) iteration number and seed of each run
+    let runs = match args.seed {
+        Some(seed) => quote! {
+            ::core::iter::once((::core::option::Option::<u64>::None, #seed))
+        },
+        None => quote! {
+            (0..#iterations).map(|iteration| {
+                let seed = ::tinyrand::SeedSequence::new(0).with_str(path).child(iteration).seed();
+                (::core::option::Option::Some(iteration), seed)
+            })
+        },
+    };
+    // under #[should_panic], a panic is the expected outcome, and is not reported
+    let (report_panic, release) = if attrs.iter().any(|attr| attr.path().is_ident("should_panic")) {
+        (quote! {}, quote! { let _ = report; })
+    } else {
+        let report_panic = quote! {
+            impl ::core::ops::Drop for Report {
+                fn drop(&mut self) {
+                    if ::std::thread::panicking() {
+                        self.print();
+                    }
+                }
+            }
+        };
+        (report_panic, quote! { ::core::mem::drop(report); })
+    };
+    let (call, ok) = if returns_unit(output) {
+        (quote! { #name(&mut rand); }, quote! {})
+    } else {
+        (
+            quote! {
+                if let ::core::result::Result::Err(err) = #name(&mut rand) {
+                    report.print();
+                    return ::core::result::Result::Err(err);
+                }
+            },
+            quote! { ::core::result::Result::Ok(()) },
+        )
+    };
+
+    Ok(quote! {
+        #(#attrs)*
+        #[test]
+        #vis fn #name() #output {
+            fn #name(#inputs) #output #block
+
+            /// Describes the current run, should it fail.
+            struct Report {
+                path: &'static str,
+                iteration: ::core::option::Option<u64>,
+                seed: u64,
+            }
+
+            impl Report {
+                #[allow(dead_code)]
+                fn print(&self) {
+                    match self.iteration {
+                        ::core::option::Option::Some(iteration) => ::std::eprintln!(
+                            "{} failed on iteration {} of {} with seed {}; replay with #[tinyrand::test(seed = {})]",
+                            self.path, iteration, #iterations, self.seed, self.seed
+                        ),
+                        ::core::option::Option::None => ::std::eprintln!("{} failed with seed {}", self.path, self.seed),
+                    }
+                }
+            }
+
+            #report_panic
+
+            let path = ::core::concat!(::core::module_path!(), "::", ::core::stringify!(#name));
+            for (iteration, seed) in #runs {
+                let report = Report { path, iteration, seed };
+                let mut rand = <::tinyrand::StdRand as ::tinyrand::Seeded>::seed(seed);
+                #call
+                #release
+            }
+            #ok
+        }
+    })
+}
+
+/// Whether the function returns `()`, either implicitly or as `-> ()`.
+fn returns_unit(output: &ReturnType) -> bool {
+    match output {
+        ReturnType::Default => true,
+        ReturnType::Type(_, ty) => matches!(&**ty, Type::Tuple(tuple) if tuple.elems.is_empty()),
+    }
+}
+
+/// Checks that the function can be run as a randomised test.
+fn validate(func: &ItemFn) -> syn::Result<()> {
+    let sig = &func.sig;
+    if let Some(asyncness) = &sig.asyncness {
+        return Err(syn::Error::new_spanned(asyncness, "randomised tests cannot be `async`"));
+    }
+    if !sig.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&sig.generics, "randomised tests cannot be generic"));
+    }
+    match sig.inputs.first() {
+        Some(FnArg::Typed(_)) if sig.inputs.len() == 1 => Ok(()),
+        _ => Err(syn::Error::new(
+            sig.paren_token.span.join(),
+            "expected a single argument for the generator, e.g., `rand: &mut StdRand`",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,106 @@
+use super::*;
+
+fn error(args: TokenStream, item: TokenStream) -> String {
+    expand(args, item).unwrap_err().to_string()
+}
+
+#[test]
+fn parse_defaults() {
+    assert_eq!(Args { iterations: 100, seed: None }, Args::parse(quote! {}).unwrap());
+}
+
+#[test]
+fn parse_options() {
+    assert_eq!(Args { iterations: 7, seed: None }, Args::parse(quote! { iterations = 7 }).unwrap());
+    assert_eq!(Args { iterations: 100, seed: Some(42) }, Args::parse(quote! { seed = 42 }).unwrap());
+    assert_eq!(
+        Args { iterations: 7, seed: Some(u64::MAX) },
+        Args::parse(quote! { seed = 18446744073709551615, iterations = 7 }).unwrap()
+    );
+}
+
+#[test]
+fn parse_invalid() {
+    let err = |args| Args::parse(args).unwrap_err().to_string();
+    assert_eq!("`iterations` must be positive", err(quote! { iterations = 0 }));
+    assert_eq!("duplicate option `iterations`", err(quote! { iterations = 1, iterations = 2 }));
+    assert_eq!("duplicate option `seed`", err(quote! { seed = 1, seed = 2 }));
+    assert_eq!("unsupported option; expected `iterations` or `seed`", err(quote! { iters = 1 }));
+    assert_eq!("number too large to fit in target type", err(quote! { seed = 18446744073709551616 }));
+    assert_eq!("expected integer literal", err(quote! { seed = "42" }));
+}
+
+#[test]
+fn expand_unit() {
+    let expanded = expand(quote! { iterations = 3 }, quote! {
+        #[ignore]
+        fn prop(rand: &mut StdRand) {
+            assert!(rand.next_u64() > 0);
+        }
+    })
+    .unwrap()
+    .to_string();
+    assert!(expanded.starts_with("# [ignore] # [test] fn prop ()"), "{expanded}");
+    assert!(expanded.contains("fn prop (rand : & mut StdRand)"), "{expanded}");
+    assert!(expanded.contains("0 .. 3u64"), "{expanded}");
+    assert!(!expanded.contains("Result"), "{expanded}");
+    assert!(expanded.contains("Drop for Report"), "{expanded}");
+}
+
+#[test]
+fn expand_should_panic() {
+    let expanded = expand(quote! {}, quote! {
+        #[should_panic]
+        fn prop(rand: &mut StdRand) {
+            assert!(rand.next_u64() < 1);
+        }
+    })
+    .unwrap()
+    .to_string();
+    assert!(expanded.starts_with("# [should_panic] # [test] fn prop ()"), "{expanded}");
+    assert!(!expanded.contains("Drop for Report"), "{expanded}");
+}
+
+#[test]
+fn expand_explicit_unit() {
+    let expanded = expand(quote! { iterations = 3 }, quote! {
+        fn prop(rand: &mut StdRand) -> () {
+            assert!(rand.next_u64() > 0);
+        }
+    })
+    .unwrap()
+    .to_string();
+    assert!(expanded.contains("fn prop () -> ()"), "{expanded}");
+    assert!(expanded.contains("prop (& mut rand) ;"), "{expanded}");
+    assert!(!expanded.contains("Result"), "{expanded}");
+}
+
+#[test]
+fn expand_result() {
+    let expanded = expand(quote! { seed = 42 }, quote! {
+        fn prop(_rand: &mut StdRand) -> Result<(), String> {
+            Ok(())
+        }
+    })
+    .unwrap()
+    .to_string();
+    assert!(expanded.contains("fn prop () -> Result < () , String >"), "{expanded}");
+    assert!(expanded.contains("42u64"), "{expanded}");
+    assert!(expanded.contains(":: core :: result :: Result :: Ok (())"), "{expanded}");
+}
+
+#[test]
+fn expand_invalid() {
+    assert_eq!(
+        "randomised tests cannot be `async`",
+        error(quote! {}, quote! { async fn prop(rand: &mut StdRand) {} })
+    );
+    assert_eq!(
+        "randomised tests cannot be generic",
+        error(quote! {}, quote! { fn prop<R: Rand>(rand: &mut R) {} })
+    );
+    let single_argument = "expected a single argument for the generator, e.g., `rand: &mut StdRand`";
+    assert_eq!(single_argument, error(quote! {}, quote! { fn prop() {} }));
+    assert_eq!(single_argument, error(quote! {}, quote! { fn prop(a: &mut StdRand, b: u64) {} }));
+    assert_eq!("expected `fn`", error(quote! {}, quote! { struct Prop; }));
+}
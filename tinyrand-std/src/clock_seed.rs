@@ -1,33 +1,128 @@
 //! Seeding from the system clock.
 
-use std::time::SystemTime;
-use tinyrand::Rand;
+use core::convert::Infallible;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::thread;
+use std::time::{Instant, SystemTime};
+use tinyrand::scramble::scramble;
+use tinyrand::{Rand, SeedSource};
 
-/// Derives a seed from the system clock by `XOR`ing the upper 64 bits of the nanosecond timestamp
-/// with the lower 64 bits.
+/// Incremented on every reading, so that readings within the same clock tick differ.
+static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Readings taken once per process, on first use.
+static ORIGIN: OnceLock<Origin> = OnceLock::new();
+
+struct Origin {
+    /// The reference point for readings of the monotonic clock.
+    start: Instant,
+
+    /// The address of a heap allocation.
+    heap: usize,
+}
+
+impl Origin {
+    fn new() -> Self {
+        let heap = Box::new(0u8);
+        Self {
+            start: Instant::now(),
+            heap: core::ptr::addr_of!(*heap) as usize,
+        }
+    }
+}
+
+/// Derives a seed from the system clock, along with other sources that vary between calls,
+/// threads and processes.
+///
+/// The following are folded into a single entropy word:
+/// * the wall-clock time, in nanoseconds relative to the Unix epoch (which may be negative);
+/// * the monotonic clock ([`Instant`]), as the time elapsed since the first reading;
+/// * a hash of the calling thread's id;
+/// * the addresses of a stack variable and of a heap allocation (made once per process), which
+///   differ between processes under address space layout randomisation.
+///
+/// The entropy word is then combined with a process-wide counter, incremented on every call,
+/// and [scrambled](scramble). As the scrambler is a bijection, readings that gather the same
+/// entropy (e.g., within one clock tick) are distinct by virtue of the counter. Obtaining a seed
+/// never panics, even if the clock is set before 1970.
 ///
 /// # Examples
 /// ```
 /// use tinyrand::{StdRand, Rand, Seeded};
 /// use tinyrand_std::ClockSeed;
-/// let mut seed = ClockSeed::default();
 ///
-/// let mut rand = StdRand::seed(seed.next_u64());
+/// let mut rand = StdRand::from_source(&mut ClockSeed).unwrap();
 /// println!("{}", rand.next_u64());
 /// ```
 #[derive(Default)]
 pub struct ClockSeed;
 
+impl ClockSeed {
+    #[inline(always)]
+    #[allow(clippy::cast_sign_loss)]
+    fn read() -> u64 {
+        let epoch = epoch_nanos(SystemTime::now()) as u128;
+        let origin = ORIGIN.get_or_init(Origin::new);
+        let elapsed = origin.start.elapsed().as_nanos();
+        let mut hasher = DefaultHasher::new();
+        thread::current().id().hash(&mut hasher);
+        let local = 0u8;
+        let entropy = fold(&[
+            epoch as u64,
+            (epoch >> 64) as u64,
+            elapsed as u64,
+            (elapsed >> 64) as u64,
+            hasher.finish(),
+            core::ptr::addr_of!(local) as usize as u64,
+            origin.heap as u64,
+        ]);
+        combine(entropy, COUNTER.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// Folds several words of entropy into one.
+fn fold(words: &[u64]) -> u64 {
+    words.iter().fold(0, |acc, &word| scramble(acc ^ word))
+}
+
+/// Combines the entropy word with the counter. For a given entropy word, this is a bijection in
+/// the counter, so distinct counter values give distinct seeds.
+#[inline(always)]
+fn combine(entropy: u64, count: u64) -> u64 {
+    scramble(entropy.wrapping_add(count))
+}
+
+/// The number of nanoseconds between the Unix epoch and the given time, negative if the time
+/// precedes the epoch.
+fn epoch_nanos(time: SystemTime) -> i128 {
+    match time.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(after) => i128::try_from(after.as_nanos()).unwrap_or(i128::MAX),
+        Err(err) => i128::try_from(err.duration().as_nanos()).map_or(i128::MIN, |before| -before),
+    }
+}
+
+impl SeedSource for ClockSeed {
+    type Error = Infallible;
+
+    #[inline(always)]
+    fn next_seed(&mut self) -> Result<u64, Self::Error> {
+        Ok(Self::read())
+    }
+}
+
+/// **Deprecated**: use [`SeedSource`] instead. Seeds are meant for initialising an RNG, not for
+/// standing in for one, so a [`ClockSeed`] should not be used as a [`Rand`]. (Trait
+/// implementations cannot carry a `#[deprecated]` attribute; this implementation will be removed
+/// in a future release.)
 impl Rand for ClockSeed {
     #[inline(always)]
     fn next_u64(&mut self) -> u64 {
-        let time = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap()
-            .as_nanos();
-        ((time >> 64) ^ time) as u64
+        Self::read()
     }
 }
 
 #[cfg(test)]
-mod tests;
\ No newline at end of file
+mod tests;
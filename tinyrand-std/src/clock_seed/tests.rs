@@ -1,8 +1,63 @@
-use tinyrand::Rand;
-use crate::ClockSeed;
+use super::*;
+use std::collections::HashSet;
+use std::time::Duration;
 
 #[test]
 fn clock_seed() {
-    let mut seed = ClockSeed::default();
+    let mut seed = ClockSeed;
     assert_ne!(0, seed.next_u64());
-}
\ No newline at end of file
+}
+
+#[test]
+fn seed_source() {
+    assert_ne!(Ok(0), ClockSeed.next_seed());
+}
+
+#[test]
+fn back_to_back_distinct() {
+    let seeds: HashSet<_> = (0..10_000).map(|_| ClockSeed::read()).collect();
+    assert_eq!(10_000, seeds.len());
+}
+
+#[test]
+fn cross_thread_distinct() {
+    let handles: Vec<_> = (0..8)
+        .map(|_| thread::spawn(|| (0..1_000).map(|_| ClockSeed::read()).collect::<Vec<_>>()))
+        .collect();
+    let seeds: HashSet<_> = handles
+        .into_iter()
+        .flat_map(|handle| handle.join().unwrap())
+        .collect();
+    assert_eq!(8_000, seeds.len());
+}
+
+#[test]
+fn epoch_nanos_after_epoch() {
+    let time = SystemTime::UNIX_EPOCH + Duration::from_nanos(1_500);
+    assert_eq!(1_500, epoch_nanos(time));
+    assert_eq!(0, epoch_nanos(SystemTime::UNIX_EPOCH));
+}
+
+#[test]
+fn epoch_nanos_before_epoch() {
+    let time = SystemTime::UNIX_EPOCH - Duration::from_secs(1);
+    assert_eq!(-1_000_000_000, epoch_nanos(time));
+}
+
+#[test]
+fn combine_distinct_counts() {
+    for entropy in [0, 1, u64::MAX, 0x0123_4567_89AB_CDEF] {
+        let seeds: HashSet<_> = (0..10_000).map(|count| combine(entropy, count)).collect();
+        assert_eq!(10_000, seeds.len());
+    }
+    assert_eq!(scramble(0), combine(0, 0));
+    assert_eq!(scramble(0), combine(u64::MAX, 1));
+}
+
+#[test]
+fn fold_words() {
+    assert_eq!(0, fold(&[]));
+    assert_eq!(scramble(42), fold(&[42]));
+    assert_eq!(scramble(scramble(1) ^ 2), fold(&[1, 2]));
+    assert_ne!(fold(&[1, 2]), fold(&[2, 1]));
+}
@@ -0,0 +1,90 @@
+//! Seeding from an environment variable.
+
+use std::env::{self, VarError};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::num::ParseIntError;
+use tinyrand::SeedSource;
+
+/// Reads the seed from an environment variable, holding a decimal `u64`. Useful for replaying a
+/// run, without changing the code that seeds it.
+///
+/// The variable is read afresh for each seed.
+///
+/// # Examples
+/// ```
+/// use tinyrand::{StdRand, Rand, Seeded};
+/// use tinyrand_std::{EnvSeed, OsSeed};
+///
+/// let mut rand = StdRand::from_source(&mut EnvSeed::new("MY_APP_SEED"))
+///     .or_else(|_| StdRand::from_source(&mut OsSeed))
+///     .unwrap();
+/// println!("{}", rand.next_u64());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvSeed {
+    name: String,
+}
+
+impl EnvSeed {
+    /// Creates an [`EnvSeed`] that reads the variable with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+
+    /// The name of the variable.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// The reasons a seed may not be read from an environment variable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvSeedError {
+    /// The variable is not set.
+    NotPresent { name: String },
+
+    /// The value of the variable is not valid Unicode.
+    NotUnicode { name: String },
+
+    /// The value of the variable is not a valid `u64`.
+    Invalid { name: String, source: ParseIntError },
+}
+
+impl Display for EnvSeedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotPresent { name } => write!(f, "environment variable {name} is not set"),
+            Self::NotUnicode { name } => write!(f, "environment variable {name} is not valid Unicode"),
+            Self::Invalid { name, source } => write!(f, "environment variable {name} is not a valid seed: {source}"),
+        }
+    }
+}
+
+impl Error for EnvSeedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Invalid { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl SeedSource for EnvSeed {
+    type Error = EnvSeedError;
+
+    fn next_seed(&mut self) -> Result<u64, Self::Error> {
+        let name = || self.name.clone();
+        let value = env::var(&self.name).map_err(|err| match err {
+            VarError::NotPresent => EnvSeedError::NotPresent { name: name() },
+            VarError::NotUnicode(_) => EnvSeedError::NotUnicode { name: name() },
+        })?;
+        value
+            .trim()
+            .parse()
+            .map_err(|source| EnvSeedError::Invalid { name: name(), source })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,46 @@
+use super::*;
+use std::env;
+
+// each test uses its own variable, as tests run concurrently
+
+#[test]
+fn valid() {
+    env::set_var("TINYRAND_TEST_ENV_SEED_VALID", " 42\n");
+    let mut source = EnvSeed::new("TINYRAND_TEST_ENV_SEED_VALID");
+    assert_eq!("TINYRAND_TEST_ENV_SEED_VALID", source.name());
+    assert_eq!(Ok(42), source.next_seed());
+}
+
+#[test]
+fn max() {
+    env::set_var("TINYRAND_TEST_ENV_SEED_MAX", u64::MAX.to_string());
+    assert_eq!(Ok(u64::MAX), EnvSeed::new("TINYRAND_TEST_ENV_SEED_MAX").next_seed());
+}
+
+#[test]
+fn not_present() {
+    let err = EnvSeed::new("TINYRAND_TEST_ENV_SEED_ABSENT").next_seed().unwrap_err();
+    assert_eq!(EnvSeedError::NotPresent { name: "TINYRAND_TEST_ENV_SEED_ABSENT".into() }, err);
+    assert_eq!("environment variable TINYRAND_TEST_ENV_SEED_ABSENT is not set", err.to_string());
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn invalid() {
+    env::set_var("TINYRAND_TEST_ENV_SEED_INVALID", "-1");
+    let err = EnvSeed::new("TINYRAND_TEST_ENV_SEED_INVALID").next_seed().unwrap_err();
+    assert!(matches!(err, EnvSeedError::Invalid { .. }), "{err:?}");
+    assert_eq!("environment variable TINYRAND_TEST_ENV_SEED_INVALID is not a valid seed: invalid digit found in string", err.to_string());
+    assert!(err.source().is_some());
+}
+
+#[test]
+#[cfg(unix)]
+fn not_unicode() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+    env::set_var("TINYRAND_TEST_ENV_SEED_NOT_UNICODE", OsStr::from_bytes(&[0xFF]));
+    let err = EnvSeed::new("TINYRAND_TEST_ENV_SEED_NOT_UNICODE").next_seed().unwrap_err();
+    assert_eq!(EnvSeedError::NotUnicode { name: "TINYRAND_TEST_ENV_SEED_NOT_UNICODE".into() }, err);
+    assert_eq!("environment variable TINYRAND_TEST_ENV_SEED_NOT_UNICODE is not valid Unicode", err.to_string());
+}
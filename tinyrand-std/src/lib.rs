@@ -1,11 +1,23 @@
 //! Extensions for using `tinyrand` with `stdlib`.
 
 pub mod clock_seed;
+pub mod env_seed;
+pub mod os_seed;
+#[cfg(feature = "rayon")]
+pub mod par;
 pub mod thread_local;
+pub mod thread_seed;
 
 pub use clock_seed::ClockSeed;
-pub use thread_local::ThreadLocalRand;
-pub use thread_local::thread_rand;
+pub use env_seed::{EnvSeed, EnvSeedError};
+pub use os_seed::OsSeed;
+pub use thread_local::{ThreadLocalRand, ThreadRandOverride};
+pub use thread_local::{override_thread_rand, override_thread_rand_of, reseed_thread_rand, reseed_thread_rand_of};
+pub use thread_local::{thread_rand, thread_rand_of, with_thread_rand};
+pub use thread_seed::{root_seed, thread_seed};
+
+#[cfg(feature = "rayon")]
+pub use par::ParallelRand;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,71 @@
+//! Seeding from the operating system's entropy source.
+
+use std::io;
+use tinyrand::SeedSource;
+
+/// Derives seeds from the kernel's cryptographically secure RNG.
+///
+/// On Linux, the `getrandom` syscall is used; on other platforms, the nearest equivalent, as
+/// chosen by the [`getrandom`](https://lib.rs/crates/getrandom) crate. On Unix-like systems,
+/// `/dev/urandom` is read if the preferred source fails (e.g., under an old kernel or a seccomp
+/// policy that forbids the syscall).
+///
+/// Unlike [`ClockSeed`](crate::ClockSeed), processes started at the same instant get distinct
+/// seeds. Failures are returned as errors rather than panicking.
+///
+/// # Examples
+/// ```
+/// use tinyrand::{StdRand, Rand, Seeded};
+/// use tinyrand_std::OsSeed;
+///
+/// let mut rand = StdRand::from_source(&mut OsSeed).expect("no entropy source");
+/// println!("{}", rand.next_u64());
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OsSeed;
+
+impl OsSeed {
+    /// Fills the given buffer with random bytes.
+    ///
+    /// # Errors
+    /// If no entropy source is available.
+    pub fn try_fill_bytes(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        match getrandom::getrandom(buf) {
+            Ok(()) => Ok(()),
+            #[cfg(unix)]
+            Err(_) => read_device("/dev/urandom", buf),
+            #[cfg(not(unix))]
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Returns a random `u64`, for use as a seed.
+    ///
+    /// # Errors
+    /// If no entropy source is available.
+    pub fn try_next_u64(&mut self) -> io::Result<u64> {
+        let mut buf = [0; 8];
+        self.try_fill_bytes(&mut buf)?;
+        Ok(u64::from_le_bytes(buf))
+    }
+}
+
+impl SeedSource for OsSeed {
+    type Error = io::Error;
+
+    #[inline(always)]
+    fn next_seed(&mut self) -> Result<u64, Self::Error> {
+        self.try_next_u64()
+    }
+}
+
+/// Fills the buffer by reading from a character device, such as `/dev/urandom`.
+#[cfg(unix)]
+fn read_device(path: &str, buf: &mut [u8]) -> io::Result<()> {
+    use std::fs::File;
+    use std::io::Read;
+    File::open(path)?.read_exact(buf)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,42 @@
+use super::*;
+use tinyrand::{Rand, Seeded, StdRand};
+
+#[test]
+fn os_seed() {
+    let mut seed = OsSeed;
+    let (a, b) = (seed.try_next_u64().unwrap(), seed.try_next_u64().unwrap());
+    assert_ne!(a, b);
+}
+
+#[test]
+fn seed_source() {
+    let mut rand = StdRand::from_source(&mut OsSeed).unwrap();
+    assert_ne!(rand.next_u64(), rand.next_u64());
+}
+
+#[test]
+fn fill_bytes() {
+    let mut buf = [0; 64];
+    OsSeed.try_fill_bytes(&mut buf).unwrap();
+    assert!(buf.iter().any(|&byte| byte != 0));
+}
+
+#[test]
+fn fill_empty() {
+    OsSeed.try_fill_bytes(&mut []).unwrap();
+}
+
+#[test]
+#[cfg(unix)]
+fn read_urandom() {
+    let mut buf = [0; 64];
+    read_device("/dev/urandom", &mut buf).unwrap();
+    assert!(buf.iter().any(|&byte| byte != 0));
+}
+
+#[test]
+#[cfg(unix)]
+fn read_missing_device() {
+    let err = read_device("/dev/no-such-device", &mut [0; 8]).unwrap_err();
+    assert_eq!(io::ErrorKind::NotFound, err.kind());
+}
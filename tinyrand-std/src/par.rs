@@ -0,0 +1,127 @@
+//! Deterministic random streams for [`rayon`] parallel iterators.
+//!
+//! Drawing from [`thread_rand`](crate::thread_rand) inside a parallel iterator is not
+//! reproducible: work stealing decides which thread processes which item, and in what order.
+//! Instead, each item is given its own [`StdRand`], seeded with a keyed hash of a root seed and
+//! the item's index. The random numbers seen by an item depend only on the root seed and the
+//! item's position in the iterator, so the results are bit-identical for any number of threads.
+
+use rayon::iter::plumbing::{Consumer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+use tinyrand::scramble::scramble;
+use tinyrand::{Rand, Seeded, SplitMix, StdRand};
+
+/// Creates the [`StdRand`] for the task at the given index, as used by
+/// [`ParallelRand::map_with_rand`].
+///
+/// The task's seed is derived by hashing the index with a key, which is in turn derived from the
+/// root seed. For a given root seed, distinct indices always produce distinct task seeds.
+///
+/// # Examples
+/// ```
+/// use tinyrand::Rand;
+/// use tinyrand_std::par::task_rand;
+/// let mut rand = task_rand(42, 7);
+/// println!("{}", rand.next_u64());
+/// ```
+pub fn task_rand(seed: u64, index: u64) -> StdRand {
+    StdRand::seed(task_seed(seed, index))
+}
+
+/// A keyed hash of the index. Both [`scramble`] and the XOR with the key are bijections, so
+/// distinct indices cannot collide under the same key.
+#[inline(always)]
+fn task_seed(seed: u64, index: u64) -> u64 {
+    let key = SplitMix::seed(seed).next_u64();
+    scramble(key ^ scramble(index))
+}
+
+/// Extends indexed parallel iterators with reproducible random number generation.
+pub trait ParallelRand: IndexedParallelIterator {
+    /// Applies `f` to each item, along with a [`StdRand`] belonging to that item alone. The
+    /// generator for the item at index `i` is [`task_rand(seed, i)`](task_rand).
+    ///
+    /// # Examples
+    /// ```
+    /// use rayon::prelude::*;
+    /// use tinyrand::Rand;
+    /// use tinyrand_std::par::ParallelRand;
+    /// let samples: Vec<u64> = (0..1_000).into_par_iter()
+    ///     .map_with_rand(42, |rand, _| rand.next_u64())
+    ///     .collect();
+    /// assert_eq!(1_000, samples.len());
+    /// ```
+    fn map_with_rand<F, T>(self, seed: u64, f: F) -> MapWithRand<Self, F>
+    where
+        F: Fn(&mut StdRand, Self::Item) -> T + Sync + Send,
+        T: Send,
+    {
+        MapWithRand { base: self, seed, f }
+    }
+}
+
+impl<I: IndexedParallelIterator> ParallelRand for I {}
+
+/// A parallel iterator that maps each item with its own [`StdRand`].
+///
+/// Created by [`ParallelRand::map_with_rand`].
+#[derive(Debug, Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MapWithRand<I, F> {
+    base: I,
+    seed: u64,
+    f: F,
+}
+
+impl<I, F, T> MapWithRand<I, F>
+where
+    I: IndexedParallelIterator,
+    F: Fn(&mut StdRand, I::Item) -> T + Sync + Send,
+    T: Send,
+{
+    /// The equivalent composition of rayon's adaptors, to which the work is delegated.
+    fn into_inner(self) -> impl IndexedParallelIterator<Item = T> {
+        let Self { base, seed, f } = self;
+        base.enumerate()
+            .map(move |(index, item)| f(&mut task_rand(seed, index as u64), item))
+    }
+}
+
+impl<I, F, T> ParallelIterator for MapWithRand<I, F>
+where
+    I: IndexedParallelIterator,
+    F: Fn(&mut StdRand, I::Item) -> T + Sync + Send,
+    T: Send,
+{
+    type Item = T;
+
+    fn drive_unindexed<C: UnindexedConsumer<T>>(self, consumer: C) -> C::Result {
+        self.into_inner().drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.base.len())
+    }
+}
+
+impl<I, F, T> IndexedParallelIterator for MapWithRand<I, F>
+where
+    I: IndexedParallelIterator,
+    F: Fn(&mut StdRand, I::Item) -> T + Sync + Send,
+    T: Send,
+{
+    fn drive<C: Consumer<T>>(self, consumer: C) -> C::Result {
+        self.into_inner().drive(consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.base.len()
+    }
+
+    fn with_producer<CB: ProducerCallback<T>>(self, callback: CB) -> CB::Output {
+        self.into_inner().with_producer(callback)
+    }
+}
+
+#[cfg(test)]
+mod tests;
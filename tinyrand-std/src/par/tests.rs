@@ -0,0 +1,85 @@
+use super::*;
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+use std::collections::HashSet;
+
+const ITEMS: usize = 10_000;
+
+fn sample_in_pool(threads: usize, seed: u64) -> Vec<(usize, u64)> {
+    let pool = ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+    pool.install(|| {
+        (0..ITEMS)
+            .into_par_iter()
+            .map_with_rand(seed, |rand, item| (item, rand.next_u64()))
+            .collect()
+    })
+}
+
+#[test]
+fn identical_for_any_thread_count() {
+    let expected: Vec<_> = (0..ITEMS)
+        .map(|item| (item, task_rand(42, item as u64).next_u64()))
+        .collect();
+    for threads in [1, 2, 3, 8] {
+        assert_eq!(expected, sample_in_pool(threads, 42), "threads: {threads}");
+    }
+}
+
+#[test]
+fn independent_of_split_granularity() {
+    let coarse: Vec<_> = (0..ITEMS)
+        .into_par_iter()
+        .with_min_len(ITEMS)
+        .map_with_rand(7, |rand, _| rand.next_u64())
+        .collect();
+    let fine: Vec<_> = (0..ITEMS)
+        .into_par_iter()
+        .with_max_len(1)
+        .map_with_rand(7, |rand, _| rand.next_u64())
+        .collect();
+    assert_eq!(coarse, fine);
+}
+
+#[test]
+fn unindexed_consumer() {
+    let sum = |seed| -> u64 {
+        (0..ITEMS)
+            .into_par_iter()
+            .map_with_rand(seed, |rand, _| rand.next_u64() >> 32)
+            .sum()
+    };
+    assert_eq!(sum(3), sum(3));
+    assert_ne!(sum(3), sum(4));
+}
+
+#[test]
+fn len() {
+    let iter = (0..17).into_par_iter().map_with_rand(0, |_, item| item);
+    assert_eq!(17, iter.len());
+    assert_eq!(Some(17), iter.opt_len());
+}
+
+#[test]
+fn zip_with_indexed() {
+    let zipped: Vec<_> = (0..100)
+        .into_par_iter()
+        .map_with_rand(0, |rand, _| rand.next_u64())
+        .zip((0..100).into_par_iter())
+        .collect();
+    let expected: Vec<_> = (0..100).map(|item| (task_rand(0, item).next_u64(), item as i32)).collect();
+    assert_eq!(expected, zipped);
+}
+
+#[test]
+fn distinct_seeds_across_indices() {
+    for seed in [0, 1, u64::MAX] {
+        let seeds: HashSet<_> = (0..ITEMS as u64).map(|index| task_seed(seed, index)).collect();
+        assert_eq!(ITEMS, seeds.len());
+    }
+}
+
+#[test]
+fn distinct_streams_across_root_seeds() {
+    assert_ne!(task_seed(0, 0), task_seed(1, 0));
+    assert_ne!(task_rand(0, 1).next_u64(), task_rand(1, 0).next_u64());
+}
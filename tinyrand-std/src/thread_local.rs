@@ -1,19 +1,47 @@
 //! Thread-local [`Rand`].
 
-use crate::ClockSeed;
+use crate::thread_seed::thread_seed;
 use core::cell::RefCell;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
 use std::rc::Rc;
 use tinyrand::{Rand, Seeded, StdRand};
 
 thread_local! {
-    static THREAD_LOCAL_RAND: Rc<RefCell<StdRand>> = Rc::new(RefCell::new(StdRand::seed(ClockSeed::default().next_u64())));
+    static THREAD_LOCAL_RAND: Rc<RefCell<StdRand>> = Rc::new(RefCell::new(StdRand::seed(thread_seed())));
+
+    /// Generators other than [`StdRand`], keyed by type; each value is an `Rc<RefCell<R>>`.
+    static THREAD_LOCAL_RANDS: RefCell<HashMap<TypeId, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// The calling thread's generator of type `G::R`, created (and seeded by `G`) on first use.
+fn thread_cell<G: Seeded>() -> Rc<RefCell<G::R>>
+where
+    G::R: 'static,
+{
+    if TypeId::of::<G::R>() == TypeId::of::<StdRand>() {
+        let cell: Box<dyn Any> = Box::new(THREAD_LOCAL_RAND.with(Clone::clone));
+        return *cell.downcast().expect("generator of the wrong type");
+    }
+    THREAD_LOCAL_RANDS.with(|rands| {
+        rands
+            .borrow_mut()
+            .entry(TypeId::of::<G::R>())
+            .or_insert_with(|| Box::new(Rc::new(RefCell::new(G::seed(thread_seed())))))
+            .downcast_ref::<Rc<RefCell<G::R>>>()
+            .expect("generator of the wrong type")
+            .clone()
+    })
 }
 
 /// A seeded, thread-local [`Rand`] instance.
+///
+/// All instances obtained on a given thread for a given generator type share the same state;
+/// reseeding the thread's generator (or overriding it) affects all of them.
 #[allow(clippy::module_name_repetitions)]
-pub struct ThreadLocalRand(Rc<RefCell<StdRand>>);
+pub struct ThreadLocalRand<R = StdRand>(Rc<RefCell<R>>);
 
-impl Rand for ThreadLocalRand {
+impl<R: Rand> Rand for ThreadLocalRand<R> {
     #[inline(always)]
     fn next_u16(&mut self) -> u16 {
         self.0.borrow_mut().next_u16()
@@ -36,10 +64,130 @@ impl Rand for ThreadLocalRand {
 }
 
 /// Obtains a seeded, thread-local [`Rand`] instance.
+///
+/// Each thread's generator is seeded by its [`thread_seed`], which is derived from a root seed
+/// read from the `TINYRAND_SEED` environment variable or, if unset, from the operating system's
+/// entropy source. Should the thread panic, its seed is reported, so that the run may be
+/// reproduced. See the [`thread_seed`](mod@crate::thread_seed) module for details.
 pub fn thread_rand() -> ThreadLocalRand {
     let cell = THREAD_LOCAL_RAND.with(std::clone::Clone::clone);
     ThreadLocalRand(cell)
 }
 
+/// Obtains a thread-local instance of the generator `G::R`, which is seeded by `G` (with a seed
+/// obtained as for [`thread_rand`]) on first use.
+///
+/// There is one generator per type per thread, however it is seeded; thus, both
+/// `thread_rand_of::<StdRand>()` and `thread_rand_of::<Scrambled<StdRand>>()` share their state
+/// with [`thread_rand`].
+///
+/// # Examples
+/// ```
+/// use tinyrand::{Rand, Xoshiro};
+/// use tinyrand_std::thread_rand_of;
+/// let mut rand = thread_rand_of::<Xoshiro>();
+/// println!("{}", rand.next_u64());
+/// ```
+pub fn thread_rand_of<G: Seeded>() -> ThreadLocalRand<G::R>
+where
+    G::R: 'static,
+{
+    ThreadLocalRand(thread_cell::<G>())
+}
+
+/// Runs the given closure on the calling thread's [`StdRand`], without the overhead of obtaining
+/// a [`ThreadLocalRand`].
+///
+/// # Panics
+/// If the closure itself draws from the thread's [`StdRand`] (e.g., through [`thread_rand`]),
+/// as the generator is already borrowed.
+///
+/// # Examples
+/// ```
+/// use tinyrand::Rand;
+/// use tinyrand_std::with_thread_rand;
+/// let roll = with_thread_rand(|rand| rand.next_lim_u64(6) + 1);
+/// assert!((1..=6).contains(&roll));
+/// ```
+pub fn with_thread_rand<T>(f: impl FnOnce(&mut StdRand) -> T) -> T {
+    THREAD_LOCAL_RAND.with(|cell| f(&mut cell.borrow_mut()))
+}
+
+/// Reseeds the calling thread's [`StdRand`], as returned by [`thread_rand`].
+///
+/// # Panics
+/// If the generator is borrowed, i.e., when called from within [`with_thread_rand`].
+pub fn reseed_thread_rand(seed: u64) {
+    reseed_thread_rand_of::<StdRand>(seed);
+}
+
+/// Reseeds the calling thread's generator of type `G::R`, as returned by [`thread_rand_of`].
+///
+/// # Panics
+/// If the generator is borrowed, i.e., when called from within [`with_thread_rand`].
+pub fn reseed_thread_rand_of<G: Seeded>(seed: u64)
+where
+    G::R: 'static,
+{
+    *thread_cell::<G>().borrow_mut() = G::seed(seed);
+}
+
+/// Temporarily reseeds the calling thread's [`StdRand`], restoring its previous state when the
+/// returned guard is dropped. Useful for making code that draws from [`thread_rand`]
+/// deterministic within a test.
+///
+/// # Panics
+/// If the generator is borrowed, i.e., when called from within [`with_thread_rand`].
+///
+/// # Examples
+/// ```
+/// use tinyrand::{Rand, Seeded, StdRand};
+/// use tinyrand_std::{override_thread_rand, thread_rand};
+/// {
+///     let _guard = override_thread_rand(42);
+///     assert_eq!(StdRand::seed(42).next_u64(), thread_rand().next_u64());
+/// }
+/// // the thread's generator carries on from where it was
+/// ```
+pub fn override_thread_rand(seed: u64) -> ThreadRandOverride {
+    override_thread_rand_of::<StdRand>(seed)
+}
+
+/// Temporarily reseeds the calling thread's generator of type `G::R`, restoring its previous
+/// state when the returned guard is dropped.
+///
+/// # Panics
+/// If the generator is borrowed, i.e., when called from within [`with_thread_rand`].
+pub fn override_thread_rand_of<G: Seeded>(seed: u64) -> ThreadRandOverride<G::R>
+where
+    G::R: 'static,
+{
+    let cell = thread_cell::<G>();
+    let previous = cell.replace(G::seed(seed));
+    ThreadRandOverride {
+        cell,
+        previous: Some(previous),
+    }
+}
+
+/// Restores a thread-local generator to its state prior to [`override_thread_rand`] (or
+/// [`override_thread_rand_of`]) when dropped.
+///
+/// Nested overrides must be dropped in the reverse order of their creation, as happens naturally
+/// with scoped guards.
+#[must_use = "the override ends as soon as the guard is dropped"]
+pub struct ThreadRandOverride<R = StdRand> {
+    cell: Rc<RefCell<R>>,
+    previous: Option<R>,
+}
+
+impl<R> Drop for ThreadRandOverride<R> {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            *self.cell.borrow_mut() = previous;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests;
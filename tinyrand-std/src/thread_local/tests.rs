@@ -1,4 +1,7 @@
 use super::*;
+use std::thread;
+use tinyrand::scramble::Scrambled;
+use tinyrand::Xoshiro;
 
 #[test]
 fn with_thread_local() {
@@ -15,4 +18,63 @@ fn with_thread_local() {
     assert_ne!(rand.next_u32() as u128, rand.next_u128());
     assert_ne!(rand.next_u64() as u128, rand.next_u128());
     assert_ne!(rand.next_usize() as u128, rand.next_u128());
-}
\ No newline at end of file
+}
+
+#[test]
+fn reseed() {
+    let mut rand = thread_rand();
+    reseed_thread_rand(42);
+    let mut expected = StdRand::seed(42);
+    assert_eq!(expected.next_u64(), rand.next_u64());
+    assert_eq!(expected.next_u64(), thread_rand().next_u64());
+    assert_eq!(expected.next_u64(), with_thread_rand(|rand| rand.next_u64()));
+}
+
+#[test]
+fn thread_rand_of_type() {
+    reseed_thread_rand_of::<Xoshiro>(42);
+    let mut expected = Xoshiro::seed(42);
+    assert_eq!(expected.next_u64(), thread_rand_of::<Xoshiro>().next_u64());
+    assert_eq!(expected.next_u64(), thread_rand_of::<Xoshiro>().next_u64());
+
+    // StdRand shares its generator with thread_rand(), however it is seeded
+    reseed_thread_rand_of::<Scrambled<StdRand>>(42);
+    assert_eq!(StdRand::seed_scrambled(42).next_u64(), thread_rand().next_u64());
+    assert_eq!(expected.next_u64(), thread_rand_of::<Xoshiro>().next_u64());
+}
+
+#[test]
+fn thread_rand_per_thread() {
+    reseed_thread_rand(42);
+    let other = thread::spawn(|| thread_rand().next_u64()).join().unwrap();
+    assert_ne!(StdRand::seed(42).next_u64(), other);
+}
+
+#[test]
+fn override_restores() {
+    reseed_thread_rand(7);
+    let mut expected = StdRand::seed(7);
+    assert_eq!(expected.next_u64(), thread_rand().next_u64());
+    {
+        let _outer = override_thread_rand(42);
+        assert_eq!(StdRand::seed(42).next_u64(), thread_rand().next_u64());
+        {
+            let _inner = override_thread_rand(43);
+            assert_eq!(StdRand::seed(43).next_u64(), thread_rand().next_u64());
+        }
+        let mut outer = StdRand::seed(42);
+        outer.next_u64();
+        assert_eq!(outer.next_u64(), thread_rand().next_u64());
+    }
+    assert_eq!(expected.next_u64(), thread_rand().next_u64());
+}
+
+#[test]
+fn override_of_type_restores() {
+    reseed_thread_rand_of::<Xoshiro>(7);
+    {
+        let _guard = override_thread_rand_of::<Xoshiro>(42);
+        assert_eq!(Xoshiro::seed(42).next_u64(), thread_rand_of::<Xoshiro>().next_u64());
+    }
+    assert_eq!(Xoshiro::seed(7).next_u64(), thread_rand_of::<Xoshiro>().next_u64());
+}
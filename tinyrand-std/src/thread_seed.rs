@@ -0,0 +1,124 @@
+//! Seeds for thread-local generators, and reproducing the runs that used them.
+//!
+//! Every thread-local generator is seeded from a single _root seed_ per process. The root seed is
+//! read from the [`TINYRAND_SEED`](SEED_VAR) environment variable if it is set; otherwise, it is
+//! drawn from [`OsSeed`] (or, failing that, [`ClockSeed`]). Each thread's seed is derived from the
+//! root seed, the thread's name, and the number of threads of the same name that derived a seed
+//! before it. Since the test harness names each test's thread after the test, a test's seed does
+//! not depend on the order in which tests are run, nor on their parallelism.
+//!
+//! When a thread that has derived a seed panics, the root seed and the thread's seed are printed
+//! after the panic message. Running again with `TINYRAND_SEED` set to the printed root seed
+//! reproduces the run. (The report is printed by a panic hook, installed when the first seed is
+//! derived; it chains to any previously installed hook. Generators that were subsequently
+//! reseeded, e.g., with [`reseed_thread_rand`](crate::reseed_thread_rand), are not reproduced.)
+//!
+//! ```sh
+//! TINYRAND_SEED=123 cargo test
+//! ```
+
+use crate::{ClockSeed, EnvSeed, EnvSeedError, OsSeed};
+use core::cell::Cell;
+use std::collections::BTreeMap;
+use std::panic;
+use std::sync::{Mutex, Once, PoisonError};
+use std::thread;
+use tinyrand::{SeedSequence, SeedSource};
+
+/// The environment variable that sets the root seed.
+pub const SEED_VAR: &str = "TINYRAND_SEED";
+
+/// The root seed, once obtained.
+static ROOT_SEED: Mutex<Option<u64>> = Mutex::new(None);
+
+/// The number of threads of each name that have derived a seed. Unnamed threads are counted
+/// under `None`.
+static ORDINALS: Mutex<BTreeMap<Option<String>, u64>> = Mutex::new(BTreeMap::new());
+
+static PANIC_HOOK: Once = Once::new();
+
+thread_local! {
+    /// The root seed and the calling thread's seed, once derived.
+    static THREAD_SEED: Cell<Option<(u64, u64)>> = const { Cell::new(None) };
+}
+
+/// The root seed from which the seeds of all threads are derived. It is obtained on first use,
+/// from [`TINYRAND_SEED`](SEED_VAR) if set, or from the operating system's entropy source.
+///
+/// # Panics
+/// If `TINYRAND_SEED` is set, but does not hold a decimal `u64`.
+pub fn root_seed() -> u64 {
+    *ROOT_SEED
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get_or_insert_with(|| read_root_seed(EnvSeed::new(SEED_VAR).next_seed()))
+}
+
+fn read_root_seed(env_seed: Result<u64, EnvSeedError>) -> u64 {
+    match env_seed {
+        Ok(seed) => seed,
+        Err(EnvSeedError::NotPresent { .. }) => OsSeed
+            .next_seed()
+            .or_else(|_| ClockSeed.next_seed())
+            .unwrap_or_else(|never| match never {}),
+        Err(err) => panic!("{err}"),
+    }
+}
+
+/// The seed of the calling thread's generators, derived from the [`root_seed`] on first use.
+///
+/// # Panics
+/// If `TINYRAND_SEED` is set, but does not hold a decimal `u64`.
+///
+/// # Examples
+/// ```
+/// use tinyrand_std::thread_seed::thread_seed;
+/// assert_eq!(thread_seed(), thread_seed());
+/// ```
+pub fn thread_seed() -> u64 {
+    if let Some((_, seed)) = THREAD_SEED.with(Cell::get) {
+        return seed;
+    }
+    let root = root_seed();
+    let name = thread::current().name().map(ToString::to_string);
+    let ordinal = {
+        let mut ordinals = ORDINALS.lock().unwrap_or_else(PoisonError::into_inner);
+        let count = ordinals.entry(name.clone()).or_default();
+        *count += 1;
+        *count - 1
+    };
+    let seed = derive(root, name.as_deref(), ordinal);
+    THREAD_SEED.with(|cell| cell.set(Some((root, seed))));
+    if !thread::panicking() {
+        PANIC_HOOK.call_once(install_panic_hook);
+    }
+    seed
+}
+
+/// Derives the seed of the `ordinal`-th thread with the given name.
+fn derive(root: u64, name: Option<&str>, ordinal: u64) -> u64 {
+    let seq = SeedSequence::new(root);
+    let seq = match name {
+        Some(name) => seq.with_u64(1).with_str(name),
+        None => seq.with_u64(0),
+    };
+    seq.with_u64(ordinal).seed()
+}
+
+fn install_panic_hook() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        previous(info);
+        if let Ok(Some((root, seed))) = THREAD_SEED.try_with(Cell::get) {
+            eprintln!("{}", report(thread::current().name(), root, seed));
+        }
+    }));
+}
+
+fn report(thread_name: Option<&str>, root: u64, seed: u64) -> String {
+    let thread_name = thread_name.unwrap_or("<unnamed>");
+    format!("note: thread '{thread_name}' seeded its thread-local generators with {seed}; run with `{SEED_VAR}={root}` to reproduce")
+}
+
+#[cfg(test)]
+mod tests;
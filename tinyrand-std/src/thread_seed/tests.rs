@@ -0,0 +1,83 @@
+use super::*;
+use std::collections::HashSet;
+use std::num::ParseIntError;
+use std::str::FromStr;
+use tinyrand::{Rand, Seeded, StdRand};
+
+#[test]
+fn thread_seed_stable() {
+    assert_eq!(thread_seed(), thread_seed());
+    assert_eq!(root_seed(), root_seed());
+}
+
+#[test]
+fn thread_seed_derived_from_name() {
+    let root = root_seed();
+    let seed = thread::Builder::new()
+        .name("thread_seed_derived_from_name".into())
+        .spawn(thread_seed)
+        .unwrap()
+        .join()
+        .unwrap();
+    assert_eq!(derive(root, Some("thread_seed_derived_from_name"), 0), seed);
+
+    // a second thread of the same name gets the next ordinal
+    let seed = thread::Builder::new()
+        .name("thread_seed_derived_from_name".into())
+        .spawn(thread_seed)
+        .unwrap()
+        .join()
+        .unwrap();
+    assert_eq!(derive(root, Some("thread_seed_derived_from_name"), 1), seed);
+}
+
+#[test]
+fn thread_rand_seeded_by_thread_seed() {
+    let (seed, value) = thread::spawn(|| (thread_seed(), crate::thread_rand().next_u64()))
+        .join()
+        .unwrap();
+    assert_eq!(StdRand::seed(seed).next_u64(), value);
+}
+
+#[test]
+fn derive_distinct() {
+    let seeds: HashSet<_> = [
+        derive(0, None, 0),
+        derive(0, None, 1),
+        derive(1, None, 0),
+        derive(0, Some(""), 0),
+        derive(0, Some("main"), 0),
+        derive(0, Some("main"), 1),
+        derive(0, Some("worker"), 0),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(7, seeds.len());
+}
+
+#[test]
+fn read_root_seed_from_env() {
+    assert_eq!(42, read_root_seed(Ok(42)));
+}
+
+#[test]
+fn read_root_seed_falls_back_when_unset() {
+    let not_present = || Err(EnvSeedError::NotPresent { name: SEED_VAR.into() });
+    assert_ne!(read_root_seed(not_present()), read_root_seed(not_present()));
+}
+
+#[test]
+#[should_panic(expected = "environment variable TINYRAND_SEED is not a valid seed")]
+fn read_root_seed_invalid() {
+    let source: ParseIntError = u64::from_str("x").unwrap_err();
+    read_root_seed(Err(EnvSeedError::Invalid { name: SEED_VAR.into(), source }));
+}
+
+#[test]
+fn report_message() {
+    assert_eq!(
+        "note: thread 'worker' seeded its thread-local generators with 7; run with `TINYRAND_SEED=42` to reproduce",
+        report(Some("worker"), 42, 7)
+    );
+    assert!(report(None, 42, 7).contains("'<unnamed>'"));
+}
@@ -3,8 +3,8 @@ use std::io::{stdout, ErrorKind, Write, BufWriter};
 use std::process::exit;
 use std::str::FromStr;
 use std::{env, io};
-use tinyrand::{Counter, Rand, Seeded, SplitMix, Wyrand, Xorshift};
-use tinyrand_std::ClockSeed;
+use tinyrand::{Counter, Rand, SeedSource, Seeded, SplitMix, Wyrand, Xorshift, Xoshiro};
+use tinyrand_std::{ClockSeed, OsSeed};
 
 fn main() {
     match generate() {
@@ -23,6 +23,7 @@ enum Generator {
     SplitMix,
     Wyrand,
     Xorshift,
+    Xoshiro,
 }
 
 impl FromStr for Generator {
@@ -34,6 +35,7 @@ impl FromStr for Generator {
             "splitmix" => Ok(Self::SplitMix),
             "wyrand" => Ok(Self::Wyrand),
             "xorshift" => Ok(Self::Xorshift),
+            "xoshiro" => Ok(Self::Xoshiro),
             _ => Err(format!("unknown generator '{s}'")),
         }
     }
@@ -59,12 +61,14 @@ impl FromStr for OutputFormat {
 fn generate() -> Result<u64, Box<dyn Error>> {
     let args: Vec<_> = env::args().collect();
     if args.len() != 5 {
-        Err("usage: {} <generator ∈ {xorshift, wyrand, counter}> <seed ∈ {clock} ∪ ℕ> <format ∈ {text, binary}> <count ∈ ℕ⁺>")?;
+        Err("usage: {} <generator ∈ {xorshift, xoshiro, splitmix, wyrand, counter}> <seed ∈ {clock, os} ∪ ℕ> <format ∈ {text, binary}> <count ∈ ℕ⁺>")?;
     }
 
     let generator = Generator::from_str(&args[1])?;
     let seed = if args[2].eq_ignore_ascii_case("clock") {
-        ClockSeed::default().next_u64()
+        ClockSeed.next_seed()?
+    } else if args[2].eq_ignore_ascii_case("os") {
+        OsSeed.next_seed()?
     } else {
         u64::from_str(&args[2])?
     };
@@ -84,6 +88,7 @@ fn generate() -> Result<u64, Box<dyn Error>> {
         Generator::SplitMix => Box::new(SplitMix::seed(seed)),
         Generator::Wyrand => Box::new(Wyrand::seed(seed)),
         Generator::Xorshift => Box::new(Xorshift::seed(seed)),
+        Generator::Xoshiro => Box::new(Xoshiro::seed(seed)),
     };
 
     match format {
@@ -138,7 +143,7 @@ fn generate_bin(count: u64, mut rand: Box<dyn Rand>) -> Result<u64, Box<dyn Erro
 }
 
 enum WriteOutcome {
-    Written(usize),
+    Written(#[allow(dead_code)] usize),
     BrokenPipe,
 }
 
@@ -1,6 +1,17 @@
-use criterion::{criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use rand::{Rng, RngCore, thread_rng};
-use tinyrand::{Probability, Rand, RandRange, SplitMix, Wyrand, Xorshift};
+use std::time::Duration;
+use tinyrand::{BitBuffer, Distribution, Probability, Rand, RandBlock, RandRange, SplitMix, Uniform, Wyrand, WyrandLanes, Xorshift, Xoshiro, XoshiroLanes};
+
+/// A limit near the top of the `u64` range, for which `next_range` recomputes the rejection
+/// threshold on almost every draw, while rarely rejecting.
+const WIDE_U64: u64 = u64::MAX - (1 << 32) + 1;
+
+/// A limit that exceeds `u64::MAX` and is not a power of two.
+const LARGE_U128: u128 = (1 << 80) + 12_345;
+
+/// The length of the buffer filled by the batch benchmarks.
+const FILL_LEN: usize = 1024;
 
 fn criterion_benchmark(c: &mut Criterion) {
     let mut rand = SplitMix::default();
@@ -19,10 +30,55 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("splitmix/next_range<u128>/large", |b| {
         b.iter(|| rand.next_range(0..1u128 << 80));
     });
+    c.bench_function("splitmix/next_lim<u128>/large", |b| {
+        b.iter(|| rand.next_lim_u128(black_box(LARGE_U128)));
+    });
+    c.bench_function("splitmix/next_lim<u128>/large/legacy", |b| {
+        b.iter(|| rand.next_lim_u128_legacy(black_box(LARGE_U128)));
+    });
+    c.bench_function("splitmix/next_range<u64>/wide", |b| {
+        b.iter(|| rand.next_range(black_box(0..WIDE_U64)));
+    });
+    c.bench_function("splitmix/next_range<Duration>", |b| {
+        b.iter(|| rand.next_range(black_box(Duration::ZERO..Duration::from_millis(17))));
+    });
+    let dist = Uniform::new(black_box(0..17u64));
+    c.bench_function("splitmix/uniform<u64>", |b| {
+        b.iter(|| dist.sample(&mut rand));
+    });
+    let dist = Uniform::new(black_box(0..17u128));
+    c.bench_function("splitmix/uniform<u128>/small", |b| {
+        b.iter(|| dist.sample(&mut rand));
+    });
+    let dist = Uniform::new(black_box(0..1u128 << 80));
+    c.bench_function("splitmix/uniform<u128>/large", |b| {
+        b.iter(|| dist.sample(&mut rand));
+    });
+    let dist = Uniform::new(black_box(0..WIDE_U64));
+    c.bench_function("splitmix/uniform<u64>/wide", |b| {
+        b.iter(|| dist.sample(&mut rand));
+    });
+    let dist = Uniform::new(black_box(Duration::ZERO..Duration::from_millis(17)));
+    c.bench_function("splitmix/uniform<Duration>", |b| {
+        b.iter(|| dist.sample(&mut rand));
+    });
     let p = Probability::new(0.5);
     c.bench_function("splitmix/next_bool", |b| {
         b.iter(|| rand.next_bool(p));
     });
+    let mut bits = BitBuffer::new(SplitMix::default());
+    c.bench_function("splitmix/bit_buffer/next_bit", |b| {
+        b.iter(|| bits.next_bit());
+    });
+    c.bench_function("splitmix/bit_buffer/next_bits", |b| {
+        b.iter(|| bits.next_bits(black_box(5)));
+    });
+    c.bench_function("splitmix/bit_buffer/next_lim/pow2", |b| {
+        b.iter(|| bits.next_lim(black_box(16)));
+    });
+    c.bench_function("splitmix/bit_buffer/next_lim/small", |b| {
+        b.iter(|| bits.next_lim(black_box(17)));
+    });
 
     let mut rand = Wyrand::default();
     c.bench_function("wyrand/next_u64", |b| {
@@ -40,10 +96,55 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("wyrand/next_range<u128>/large", |b| {
         b.iter(|| rand.next_range(0..1u128 << 80));
     });
+    c.bench_function("wyrand/next_lim<u128>/large", |b| {
+        b.iter(|| rand.next_lim_u128(black_box(LARGE_U128)));
+    });
+    c.bench_function("wyrand/next_lim<u128>/large/legacy", |b| {
+        b.iter(|| rand.next_lim_u128_legacy(black_box(LARGE_U128)));
+    });
+    c.bench_function("wyrand/next_range<u64>/wide", |b| {
+        b.iter(|| rand.next_range(black_box(0..WIDE_U64)));
+    });
+    c.bench_function("wyrand/next_range<Duration>", |b| {
+        b.iter(|| rand.next_range(black_box(Duration::ZERO..Duration::from_millis(17))));
+    });
+    let dist = Uniform::new(black_box(0..17u64));
+    c.bench_function("wyrand/uniform<u64>", |b| {
+        b.iter(|| dist.sample(&mut rand));
+    });
+    let dist = Uniform::new(black_box(0..17u128));
+    c.bench_function("wyrand/uniform<u128>/small", |b| {
+        b.iter(|| dist.sample(&mut rand));
+    });
+    let dist = Uniform::new(black_box(0..1u128 << 80));
+    c.bench_function("wyrand/uniform<u128>/large", |b| {
+        b.iter(|| dist.sample(&mut rand));
+    });
+    let dist = Uniform::new(black_box(0..WIDE_U64));
+    c.bench_function("wyrand/uniform<u64>/wide", |b| {
+        b.iter(|| dist.sample(&mut rand));
+    });
+    let dist = Uniform::new(black_box(Duration::ZERO..Duration::from_millis(17)));
+    c.bench_function("wyrand/uniform<Duration>", |b| {
+        b.iter(|| dist.sample(&mut rand));
+    });
     let p = Probability::new(0.5);
     c.bench_function("wyrand/next_bool", |b| {
         b.iter(|| rand.next_bool(p));
     });
+    let mut bits = BitBuffer::new(Wyrand::default());
+    c.bench_function("wyrand/bit_buffer/next_bit", |b| {
+        b.iter(|| bits.next_bit());
+    });
+    c.bench_function("wyrand/bit_buffer/next_bits", |b| {
+        b.iter(|| bits.next_bits(black_box(5)));
+    });
+    c.bench_function("wyrand/bit_buffer/next_lim/pow2", |b| {
+        b.iter(|| bits.next_lim(black_box(16)));
+    });
+    c.bench_function("wyrand/bit_buffer/next_lim/small", |b| {
+        b.iter(|| bits.next_lim(black_box(17)));
+    });
     
     let mut rand = Xorshift::default();
     c.bench_function("xorshift/next_u64", |b| {
@@ -61,10 +162,100 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("xorshift/next_range<u128>/large", |b| {
         b.iter(|| rand.next_range(0..1u128 << 80));
     });
+    c.bench_function("xorshift/next_lim<u128>/large", |b| {
+        b.iter(|| rand.next_lim_u128(black_box(LARGE_U128)));
+    });
+    c.bench_function("xorshift/next_lim<u128>/large/legacy", |b| {
+        b.iter(|| rand.next_lim_u128_legacy(black_box(LARGE_U128)));
+    });
+    c.bench_function("xorshift/next_range<u64>/wide", |b| {
+        b.iter(|| rand.next_range(black_box(0..WIDE_U64)));
+    });
+    c.bench_function("xorshift/next_range<Duration>", |b| {
+        b.iter(|| rand.next_range(black_box(Duration::ZERO..Duration::from_millis(17))));
+    });
+    let dist = Uniform::new(black_box(0..17u64));
+    c.bench_function("xorshift/uniform<u64>", |b| {
+        b.iter(|| dist.sample(&mut rand));
+    });
+    let dist = Uniform::new(black_box(0..17u128));
+    c.bench_function("xorshift/uniform<u128>/small", |b| {
+        b.iter(|| dist.sample(&mut rand));
+    });
+    let dist = Uniform::new(black_box(0..1u128 << 80));
+    c.bench_function("xorshift/uniform<u128>/large", |b| {
+        b.iter(|| dist.sample(&mut rand));
+    });
+    let dist = Uniform::new(black_box(0..WIDE_U64));
+    c.bench_function("xorshift/uniform<u64>/wide", |b| {
+        b.iter(|| dist.sample(&mut rand));
+    });
+    let dist = Uniform::new(black_box(Duration::ZERO..Duration::from_millis(17)));
+    c.bench_function("xorshift/uniform<Duration>", |b| {
+        b.iter(|| dist.sample(&mut rand));
+    });
     let p = Probability::new(0.5);
     c.bench_function("xorshift/next_bool", |b| {
         b.iter(|| rand.next_bool(p));
     });
+    let mut bits = BitBuffer::new(Xorshift::default());
+    c.bench_function("xorshift/bit_buffer/next_bit", |b| {
+        b.iter(|| bits.next_bit());
+    });
+    c.bench_function("xorshift/bit_buffer/next_bits", |b| {
+        b.iter(|| bits.next_bits(black_box(5)));
+    });
+    c.bench_function("xorshift/bit_buffer/next_lim/pow2", |b| {
+        b.iter(|| bits.next_lim(black_box(16)));
+    });
+    c.bench_function("xorshift/bit_buffer/next_lim/small", |b| {
+        b.iter(|| bits.next_lim(black_box(17)));
+    });
+
+    let mut rand = Xoshiro::default();
+    c.bench_function("xoshiro/next_u64", |b| {
+        b.iter(|| rand.next_u64());
+    });
+    c.bench_function("xoshiro/next_u128", |b| {
+        b.iter(|| rand.next_u128());
+    });
+    c.bench_function("xoshiro/next_range<u64>", |b| {
+        b.iter(|| rand.next_range(0..17u64));
+    });
+    c.bench_function("xoshiro/next_bool", |b| {
+        b.iter(|| rand.next_bool(p));
+    });
+
+    let mut buf = [0u64; FILL_LEN];
+    let mut rand = Wyrand::default();
+    c.bench_function("wyrand/fill_u64", |b| {
+        b.iter(|| buf.iter_mut().for_each(|x| *x = rand.next_u64()));
+    });
+    let mut lanes = WyrandLanes::<4>::seed(0);
+    c.bench_function("wyrand_lanes<4>/fill_u64", |b| {
+        b.iter(|| lanes.fill_u64(&mut buf));
+    });
+    let mut lanes = WyrandLanes::<8>::seed(0);
+    c.bench_function("wyrand_lanes<8>/fill_u64", |b| {
+        b.iter(|| lanes.fill_u64(&mut buf));
+    });
+    let mut rand = Xoshiro::default();
+    c.bench_function("xoshiro/fill_u64", |b| {
+        b.iter(|| buf.iter_mut().for_each(|x| *x = rand.next_u64()));
+    });
+    let mut lanes = XoshiroLanes::<4>::seed(0);
+    c.bench_function("xoshiro_lanes<4>/fill_u64", |b| {
+        b.iter(|| lanes.fill_u64(&mut buf));
+    });
+    let mut lanes = XoshiroLanes::<8>::seed(0);
+    c.bench_function("xoshiro_lanes<8>/fill_u64", |b| {
+        b.iter(|| lanes.fill_u64(&mut buf));
+    });
+    let mut buf = [0f64; FILL_LEN];
+    let mut lanes = WyrandLanes::<8>::seed(0);
+    c.bench_function("wyrand_lanes<8>/fill_f64", |b| {
+        b.iter(|| lanes.fill_f64(&mut buf));
+    });
 
     let mut rand = thread_rng();
     c.bench_function("rand/next_u64", |b| {
@@ -0,0 +1,74 @@
+//! The [Bernoulli distribution](https://en.wikipedia.org/wiki/Bernoulli_distribution).
+
+use crate::{Distribution, Probability, Rand, Repr};
+
+/// The Bernoulli distribution, being the outcome of a single trial with a probability `p` of
+/// success.
+///
+/// Unlike [`Rand::next_bool`], which derives the sampling cutoff from the probability on every call,
+/// the cutoff is computed once, upfront. The sampled outcomes are identical to those of
+/// [`Rand::next_bool`] given the same probability and random stream.
+///
+/// # Examples
+/// ```
+/// use tinyrand::{Bernoulli, Distribution, Probability, StdRand};
+/// let dist = Bernoulli::new(Probability::from_ratio(1, 3));
+/// let mut rand = StdRand::default();
+/// println!("{}", dist.sample(&mut rand));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bernoulli {
+    p: Probability,
+    method: Method,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Method {
+    Cutoff(u64),
+    Ratio { numerator: u64, denominator: u64 },
+}
+
+impl Bernoulli {
+    /// Creates a new [`Bernoulli`] distribution with the given probability of success.
+    #[inline(always)]
+    pub fn new(p: Probability) -> Self {
+        let method = match p.0 {
+            #[allow(clippy::cast_precision_loss)]
+            #[allow(clippy::cast_sign_loss)]
+            Repr::Float(p) => Method::Cutoff((p * u64::MAX as f64) as u64),
+            Repr::Ratio { numerator, denominator } => Method::Ratio { numerator, denominator },
+        };
+        Self { p, method }
+    }
+
+    /// The probability of success.
+    pub fn p(&self) -> Probability {
+        self.p
+    }
+
+    /// Equivalent to [`Distribution::sample`], but also accepts unsized (e.g., `dyn`) RNGs.
+    #[inline(always)]
+    pub(crate) fn sample_unsized<R: Rand + ?Sized>(&self, rand: &mut R) -> bool {
+        match self.method {
+            Method::Cutoff(cutoff) => {
+                let mut next = rand.next_u64();
+                if next == u64::MAX {
+                    // guarantees that gen_bool(p=1.0) is never true
+                    next = u64::MAX - 1;
+                }
+                next < cutoff
+            }
+            Method::Ratio { numerator, denominator } => rand.next_lim_u64(denominator) < numerator,
+        }
+    }
+}
+
+impl Distribution<bool> for Bernoulli {
+    #[inline(always)]
+    fn sample(&self, rand: &mut impl Rand) -> bool {
+        self.sample_unsized(rand)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,72 @@
+use crate::bernoulli::Bernoulli;
+use crate::test_mock::{echo, fixed, TestMock};
+use crate::{Distribution, Probability, Rand, RefCellExt};
+use core::cell::RefCell;
+
+#[test]
+fn float_matches_next_bool() {
+    let cell = RefCell::default();
+    let mut rand = TestMock::new(echo(&cell));
+    for p in [0.0, f64::EPSILON, 0.25, 0.5, 1.0 / 3.0, 1.0 - f64::EPSILON, 1.0] {
+        let dist = Bernoulli::new(Probability::new(p));
+        for next in [0, 1, u64::MAX / 4, u64::MAX / 3, u64::MAX / 2, u64::MAX - 1, u64::MAX] {
+            cell.set(next);
+            assert_eq!(rand.next_bool(Probability::new(p)), dist.sample(&mut rand), "p={p}, next={next}");
+        }
+    }
+}
+
+#[test]
+fn float_bounds() {
+    let cell = RefCell::default();
+    let mut rand = TestMock::new(echo(&cell));
+    let never = Bernoulli::new(Probability::new(0.0));
+    let always = Bernoulli::new(Probability::new(1.0));
+    for next in [0, u64::MAX / 2, u64::MAX] {
+        cell.set(next);
+        assert!(!never.sample(&mut rand));
+        assert!(always.sample(&mut rand));
+    }
+}
+
+#[test]
+fn ratio_is_exact() {
+    // next_lim_u64(3) maps the lowest third of the u64 range to 0, which is the only successful outcome
+    let dist = Bernoulli::new(Probability::from_ratio(1, 3));
+    let mut rand = TestMock::new(fixed(1));
+    assert!(dist.sample(&mut rand));
+    let mut rand = TestMock::new(fixed(u64::MAX / 3));
+    assert!(dist.sample(&mut rand));
+    let mut rand = TestMock::new(fixed(u64::MAX / 3 + 1));
+    assert!(!dist.sample(&mut rand));
+    let mut rand = TestMock::new(fixed(u64::MAX));
+    assert!(!dist.sample(&mut rand));
+}
+
+#[test]
+fn ratio_bounds() {
+    let never = Bernoulli::new(Probability::from_ratio(0, 7));
+    let always = Bernoulli::new(Probability::from_ratio(7, 7));
+    for next in [1, u64::MAX / 2, u64::MAX] {
+        let mut rand = TestMock::new(fixed(next));
+        assert!(!never.sample(&mut rand));
+        assert!(always.sample(&mut rand));
+    }
+}
+
+#[test]
+fn ratio_draws_one_number() {
+    let mut rand = TestMock::new(fixed(u64::MAX / 2));
+    let dist = Bernoulli::new(Probability::from_ratio(5, 8));
+    for _ in 0..10 {
+        dist.sample(&mut rand);
+    }
+    assert_eq!(10, rand.state().next_u64_invocations());
+}
+
+#[test]
+fn p() {
+    let p = Probability::from_ratio(3, 4);
+    assert_eq!(p, Bernoulli::new(p).p());
+    assert_eq!(0.75, f64::from(Bernoulli::new(Probability::new(0.75)).p()));
+}
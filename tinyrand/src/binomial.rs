@@ -0,0 +1,243 @@
+//! The [binomial distribution](https://en.wikipedia.org/wiki/Binomial_distribution).
+
+use crate::{Distribution, Rand};
+
+/// The binomial distribution, being the number of successes in `n` independent Bernoulli
+/// trials, each with a probability `p` of success.
+///
+/// When the expected number of failures or successes is small (under 10), samples are generated by
+/// inversion. Otherwise, the BTRD algorithm of W. Hörmann (transformed rejection with decomposition)
+/// is used, whose expected cost is constant, irrespective of `n`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Binomial {
+    n: u64,
+    p: f64,
+    method: Method,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Method {
+    Constant(u64),
+    Inversion { q_pow_n: f64, s: f64, a: f64 },
+    Btrd(Btrd),
+}
+
+/// Precomputed constants for the BTRD algorithm.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Btrd {
+    m: f64,
+    r: f64,
+    nr: f64,
+    npq: f64,
+    a: f64,
+    b: f64,
+    c: f64,
+    alpha: f64,
+    v_r: f64,
+    u_rv_r: f64,
+    h: f64,
+}
+
+impl Binomial {
+    /// Creates a new [`Binomial`] distribution over `n` trials, each with a probability `p` of success.
+    ///
+    /// # Panics
+    /// If `p < 0` or `p > 1`.
+    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::float_cmp)]
+    pub fn new(n: u64, p: f64) -> Self {
+        assert!(p >= 0f64, "p ({p}) cannot be less than 0");
+        assert!(p <= 1f64, "p ({p}) cannot be greater than 1");
+
+        // sample the number of failures (and flip) if successes are more likely
+        let p_min = p.min(1.0 - p);
+        let method = if n == 0 || p == 0.0 {
+            Method::Constant(0)
+        } else if p == 1.0 {
+            Method::Constant(n)
+        } else if (n as f64) * p_min < 10.0 {
+            // (1 - p)^n, computed without rounding 1 - p, which would lose the precision of a tiny p
+            let s = p_min / (1.0 - p_min);
+            Method::Inversion {
+                q_pow_n: libm::exp(n as f64 * libm::log1p(-p_min)),
+                s,
+                a: (n as f64 + 1.0) * s,
+            }
+        } else {
+            Method::Btrd(Btrd::new(n as f64, p_min))
+        };
+        Self { n, p, method }
+    }
+}
+
+impl Distribution<u64> for Binomial {
+    fn sample(&self, rand: &mut impl Rand) -> u64 {
+        let successes = match &self.method {
+            Method::Constant(k) => return *k,
+            Method::Inversion { q_pow_n, s, a } => inversion(self.n, *q_pow_n, *s, *a, rand),
+            Method::Btrd(btrd) => btrd.sample(self.n, rand),
+        };
+        if self.p > 0.5 {
+            self.n - successes
+        } else {
+            successes
+        }
+    }
+}
+
+/// The BINV algorithm of V. Kachitvichyanukul and B. Schmeiser.
+#[allow(clippy::cast_precision_loss)]
+#[allow(clippy::many_single_char_names)]
+fn inversion(n: u64, q_pow_n: f64, s: f64, a: f64, rand: &mut impl Rand) -> u64 {
+    'outer: loop {
+        let mut r = q_pow_n;
+        let mut u = rand.next_f64();
+        let mut k = 0;
+        while u > r {
+            u -= r;
+            k += 1;
+            if k > n {
+                // accumulated rounding error has exhausted the mass; start over
+                continue 'outer;
+            }
+            r *= a / k as f64 - s;
+        }
+        return k;
+    }
+}
+
+impl Btrd {
+    #[allow(clippy::many_single_char_names)]
+    fn new(n: f64, p: f64) -> Self {
+        let q = 1.0 - p;
+        let m = libm::floor((n + 1.0) * p);
+        let r = p / q;
+        let nr = (n + 1.0) * r;
+        let npq = n * p * q;
+        let sqrt_npq = libm::sqrt(npq);
+        let b = 1.15 + 2.53 * sqrt_npq;
+        let a = -0.0873 + 0.0248 * b + 0.01 * p;
+        let c = n * p + 0.5;
+        let alpha = (2.83 + 5.1 / b) * sqrt_npq;
+        let v_r = 0.92 - 4.2 / b;
+        let u_rv_r = 0.86 * v_r;
+        let nm = n - m + 1.0;
+        let h = (m + 0.5) * libm::log((m + 1.0) / (r * nm)) + stirling_correction(m) + stirling_correction(n - m);
+        Self {
+            m,
+            r,
+            nr,
+            npq,
+            a,
+            b,
+            c,
+            alpha,
+            v_r,
+            u_rv_r,
+            h,
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::many_single_char_names)]
+    fn sample(&self, n: u64, rand: &mut impl Rand) -> u64 {
+        let n_f = n as f64;
+        loop {
+            let mut v = rand.next_f64();
+            if v <= self.u_rv_r {
+                let u = v / self.v_r - 0.43;
+                let k = libm::floor((2.0 * self.a / (0.5 - u.abs()) + self.b) * u + self.c) as u64;
+                return k.min(n);
+            }
+
+            let u = if v >= self.v_r {
+                rand.next_f64() - 0.5
+            } else {
+                let u = v / self.v_r - 0.93;
+                v = rand.next_f64() * self.v_r;
+                0.5f64.copysign(u) - u
+            };
+
+            let us = 0.5 - u.abs();
+            let k = libm::floor((2.0 * self.a / us + self.b) * u + self.c);
+            if k < 0.0 || k > n_f {
+                continue;
+            }
+            v = v * self.alpha / (self.a / (us * us) + self.b);
+            let km = (k - self.m).abs();
+
+            // the recursion counts in steps of 1.0, which is only sound where f64 represents
+            // every integer; beyond that, the squeeze is always used
+            if km <= 15.0 && n_f < MAX_EXACT_INT {
+                // recursive evaluation of f(k)
+                let mut f = 1.0;
+                let mut i = self.m;
+                while i < k {
+                    i += 1.0;
+                    f *= self.nr / i - self.r;
+                }
+                let mut i = k;
+                while i < self.m {
+                    i += 1.0;
+                    v *= self.nr / i - self.r;
+                }
+                if v <= f {
+                    return k as u64;
+                }
+                continue;
+            }
+
+            // squeeze acceptance and rejection
+            let v = libm::log(v);
+            let rho = (km / self.npq) * (((km / 3.0 + 0.625) * km + 1.0 / 6.0) / self.npq + 0.5);
+            let t = -km * km / (2.0 * self.npq);
+            if v < t - rho {
+                return k as u64;
+            }
+            if v > t + rho {
+                continue;
+            }
+
+            // final acceptance and rejection
+            let nm = n_f - self.m + 1.0;
+            let nk = n_f - k + 1.0;
+            if v <= self.h + (n_f + 1.0) * libm::log(nm / nk) + (k + 0.5) * libm::log(nk * self.r / (k + 1.0))
+                - stirling_correction(k)
+                - stirling_correction(n_f - k)
+            {
+                return k as u64;
+            }
+        }
+    }
+}
+
+/// The largest power of two below which every integer is exactly representable as an `f64`,
+/// with a margin for incrementing.
+const MAX_EXACT_INT: f64 = 4_503_599_627_370_496.0; // 2^52
+
+/// The error term of Stirling's approximation of ln(_k_!), tabulated for small _k_.
+#[allow(clippy::cast_sign_loss)]
+fn stirling_correction(k: f64) -> f64 {
+    const TABLE: [f64; 10] = [
+        0.081_061_466_795_327_26,
+        0.041_340_695_955_409_29,
+        0.027_677_925_684_998_34,
+        0.020_790_672_103_765_09,
+        0.016_644_691_189_821_19,
+        0.013_876_128_823_070_75,
+        0.011_896_709_945_891_77,
+        0.010_411_265_261_972_09,
+        0.009_255_462_182_712_733,
+        0.008_330_563_433_362_87,
+    ];
+    if k < 10.0 {
+        TABLE[k as usize]
+    } else {
+        let k_plus_1_sq = (k + 1.0) * (k + 1.0);
+        (1.0 / 12.0 - (1.0 / 360.0 - 1.0 / 1260.0 / k_plus_1_sq) / k_plus_1_sq) / (k + 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests;
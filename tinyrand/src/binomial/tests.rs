@@ -0,0 +1,102 @@
+use crate::binomial::{stirling_correction, Binomial, Method};
+use crate::{Distribution, Wyrand};
+
+#[allow(clippy::cast_precision_loss)]
+fn assert_moments(n: u64, p: f64) {
+    let mut rand = Wyrand::default();
+    let dist = Binomial::new(n, p);
+    const N: u32 = 50_000;
+    let (mut sum, mut sum_sq) = (0.0, 0.0);
+    for _ in 0..N {
+        let k = dist.sample(&mut rand);
+        assert!(k <= n, "for n={n}, p={p}, sampled {k}");
+        let k = k as f64;
+        sum += k;
+        sum_sq += k * k;
+    }
+    let mean = sum / f64::from(N);
+    let variance = sum_sq / f64::from(N) - mean * mean;
+    let expected_mean = n as f64 * p;
+    let expected_variance = expected_mean * (1.0 - p);
+    let tolerance = 5.0 * libm::sqrt(expected_variance / f64::from(N));
+    assert!(
+        (mean - expected_mean).abs() <= tolerance,
+        "for n={n}, p={p}, mean={mean}"
+    );
+    assert!(
+        (variance - expected_variance).abs() < 0.05 * expected_variance,
+        "for n={n}, p={p}, variance={variance}"
+    );
+}
+
+#[test]
+fn binomial_moments_by_inversion() {
+    assert_moments(10, 0.5);
+    assert_moments(100, 0.05);
+    assert_moments(100, 0.95);
+    assert_moments(1_000_000, 1e-6);
+    assert_moments(1_000_000_000_000, 1e-12);
+}
+
+#[test]
+fn inversion_mass_for_tiny_p() {
+    // (1 - 1e-12)^1e12 = e^(-1 - 5e-13 - ...); rounding 1 - p first would be off by about 1e-4
+    let Method::Inversion { q_pow_n, .. } = Binomial::new(1_000_000_000_000, 1e-12).method else {
+        panic!("expected inversion");
+    };
+    let expected = libm::exp(-1.0 - 5e-13);
+    assert!((q_pow_n - expected).abs() < 1e-15, "q_pow_n={q_pow_n}");
+}
+
+#[test]
+fn binomial_moments_by_btrd() {
+    assert_moments(100, 0.5);
+    assert_moments(1_000, 0.25);
+    assert_moments(1_000, 0.75);
+    assert_moments(1_000_000_000, 0.3);
+}
+
+#[test]
+fn binomial_within_range_for_huge_n() {
+    let mut rand = Wyrand::default();
+    for p in [1e-18, 0.1, 0.5, 0.9, 1.0 - 1e-12] {
+        let dist = Binomial::new(u64::MAX, p);
+        for _ in 0..1_000 {
+            // the only out-of-range outcome would be a subtraction overflow when flipping
+            dist.sample(&mut rand);
+        }
+    }
+}
+
+#[test]
+fn binomial_degenerate() {
+    let mut rand = Wyrand::default();
+    assert_eq!(0, Binomial::new(0, 0.5).sample(&mut rand));
+    assert_eq!(0, Binomial::new(100, 0.0).sample(&mut rand));
+    assert_eq!(100, Binomial::new(100, 1.0).sample(&mut rand));
+}
+
+#[test]
+fn stirling_correction_continuity() {
+    // the tabulated values and the series should agree at the boundary
+    let series = |k: f64| {
+        let k_plus_1_sq = (k + 1.0) * (k + 1.0);
+        (1.0 / 12.0 - (1.0 / 360.0 - 1.0 / 1260.0 / k_plus_1_sq) / k_plus_1_sq) / (k + 1.0)
+    };
+    for k in 7..10 {
+        let k = f64::from(k);
+        assert!((stirling_correction(k) - series(k)).abs() < 1e-9, "k={k}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "p (-0.1) cannot be less than 0")]
+fn negative_p() {
+    Binomial::new(10, -0.1);
+}
+
+#[test]
+#[should_panic(expected = "p (1.1) cannot be greater than 1")]
+fn p_greater_than_one() {
+    Binomial::new(10, 1.1);
+}
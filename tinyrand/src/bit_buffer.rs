@@ -0,0 +1,147 @@
+//! Bit-level generation, for when whole words would be wasted.
+
+use crate::Rand;
+use core::cmp::Ordering;
+
+/// Limits above this are sampled from whole words, as buffering would save few bits.
+const MAX_BUFFERED_LIM: u64 = 1 << 32;
+
+/// The number of bits, beyond those needed to represent the limit, drawn by [`BitBuffer::next_lim`],
+/// bounding the probability of rejection to 2<sup>-`EXTRA_BITS`</sup>.
+const EXTRA_BITS: u32 = 8;
+
+/// An adapter that caches the unused bits of each word generated by the underlying [`Rand`],
+/// serving them to callers that need only a few bits at a time.
+///
+/// A fair coin flip consumes a single bit, rather than an entire `u64`. Bits are consumed from
+/// the least significant end of each word; once all 64 bits of a word are used, the next word is
+/// generated.
+///
+/// # Examples
+/// ```
+/// use tinyrand::{BitBuffer, StdRand};
+/// let mut bits = BitBuffer::new(StdRand::default());
+/// let heads = (0..64).filter(|_| bits.next_bit()).count(); // from a single u64
+/// let die = bits.next_lim(6) + 1;
+/// assert!((1..=6).contains(&die));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct BitBuffer<R: Rand> {
+    rand: R,
+    bits: u64,
+    remaining: u32,
+}
+
+impl<R: Rand> BitBuffer<R> {
+    /// Creates a new [`BitBuffer`] over the given [`Rand`], with no bits cached.
+    pub fn new(rand: R) -> Self {
+        Self {
+            rand,
+            bits: 0,
+            remaining: 0,
+        }
+    }
+
+    /// The number of cached bits, which will be served before another word is generated.
+    pub fn remaining(&self) -> u32 {
+        self.remaining
+    }
+
+    /// A mutable reference to the underlying [`Rand`]. Drawing from it does not affect the cache.
+    pub fn rand_mut(&mut self) -> &mut R {
+        &mut self.rand
+    }
+
+    /// Consumes the adapter, returning the underlying [`Rand`]. Any cached bits are discarded.
+    pub fn into_inner(self) -> R {
+        self.rand
+    }
+
+    /// Returns the next random bit. Equivalent to a fair coin flip.
+    #[inline(always)]
+    pub fn next_bit(&mut self) -> bool {
+        if self.remaining == 0 {
+            self.bits = self.rand.next_u64();
+            self.remaining = 64;
+        }
+        let bit = self.bits & 1 == 1;
+        self.bits >>= 1;
+        self.remaining -= 1;
+        bit
+    }
+
+    /// Returns a `u64` whose lowest `n` bits are random, the remaining being zero.
+    ///
+    /// # Panics
+    /// If `n > 64`.
+    #[inline(always)]
+    pub fn next_bits(&mut self, n: u32) -> u64 {
+        assert!(n <= 64, "n ({n}) cannot be greater than 64");
+        match n.cmp(&self.remaining) {
+            Ordering::Less => {
+                // n < 64, so neither the mask nor the shift can overflow
+                let bits = self.bits & ((1 << n) - 1);
+                self.bits >>= n;
+                self.remaining -= n;
+                bits
+            }
+            Ordering::Equal => {
+                let bits = self.bits;
+                self.bits = 0;
+                self.remaining = 0;
+                bits
+            }
+            Ordering::Greater => {
+                // use up the cached bits, taking the rest from a new word
+                let (low, have) = (self.bits, self.remaining);
+                let word = self.rand.next_u64();
+                let need = n - have;
+                self.bits = word.checked_shr(need).unwrap_or(0);
+                self.remaining = 64 - need;
+                low | (word & mask(need)) << have
+            }
+        }
+    }
+
+    /// Generates a random number in `0..lim`.
+    ///
+    /// Powers of two consume exactly as many bits as needed. Other limits up to 2<sup>32</sup>
+    /// use the method of D. Lemire over a random number of _n_ + 8 bits, where _n_ is the
+    /// number of bits needed to represent `lim - 1`. Fewer than one in 256 draws is rejected,
+    /// so that the rejection branch is rarely taken. Larger limits are sampled from whole words,
+    /// using [`Rand::next_lim_u64`].
+    ///
+    /// # Panics
+    /// If `lim` is zero.
+    #[inline(always)]
+    pub fn next_lim(&mut self, lim: u64) -> u64 {
+        assert_ne!(0, lim, "zero limit");
+        if lim.is_power_of_two() {
+            self.next_bits(lim.trailing_zeros())
+        } else if lim <= MAX_BUFFERED_LIM {
+            let width = u64::BITS - (lim - 1).leading_zeros() + EXTRA_BITS;
+            let lim = u128::from(lim);
+            let mut full = u128::from(self.next_bits(width)) * lim;
+            let mut low = full & u128::from(mask(width));
+            if low < lim {
+                let cutoff = (1 << width) % lim;
+                while low < cutoff {
+                    full = u128::from(self.next_bits(width)) * lim;
+                    low = full & u128::from(mask(width));
+                }
+            }
+            (full >> width) as u64
+        } else {
+            self.rand.next_lim_u64(lim)
+        }
+    }
+}
+
+/// A mask of the lowest `n` bits, for `n` in `0..=64`.
+#[inline(always)]
+fn mask(n: u32) -> u64 {
+    u64::MAX.checked_shr(64 - n).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests;
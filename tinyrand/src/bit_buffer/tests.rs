@@ -0,0 +1,151 @@
+use crate::bit_buffer::{mask, BitBuffer};
+use crate::test_mock::{counter, fixed, TestMock};
+use crate::{Rand, Seeded, Wyrand};
+
+#[test]
+fn test_mask() {
+    assert_eq!(0, mask(0));
+    assert_eq!(1, mask(1));
+    assert_eq!(0xFF, mask(8));
+    assert_eq!(u64::MAX >> 1, mask(63));
+    assert_eq!(u64::MAX, mask(64));
+}
+
+#[test]
+fn next_bit_from_lsb() {
+    let mut bits = BitBuffer::new(TestMock::new(fixed(0b1011)));
+    assert_eq!(0, bits.remaining());
+    assert!(bits.next_bit());
+    assert_eq!(63, bits.remaining());
+    assert!(bits.next_bit());
+    assert!(!bits.next_bit());
+    assert!(bits.next_bit());
+    for _ in 4..64 {
+        assert!(!bits.next_bit());
+    }
+    assert_eq!(0, bits.remaining());
+    assert_eq!(1, bits.rand_mut().state().next_u64_invocations());
+
+    // the next word is only generated when needed
+    assert!(bits.next_bit());
+    assert_eq!(2, bits.rand_mut().state().next_u64_invocations());
+}
+
+#[test]
+fn next_bits_within_word() {
+    let mut bits = BitBuffer::new(TestMock::new(fixed(0x1234_5678_9ABC_DEF0)));
+    assert_eq!(0, bits.next_bits(0));
+    assert_eq!(0, bits.remaining());
+    assert_eq!(0xF0, bits.next_bits(8));
+    assert_eq!(0xDE, bits.next_bits(8));
+    assert_eq!(0x9ABC, bits.next_bits(16));
+    assert_eq!(0x1234_5678, bits.next_bits(32));
+    assert_eq!(0, bits.remaining());
+    assert_eq!(0, bits.next_bits(0));
+    assert_eq!(1, bits.rand_mut().state().next_u64_invocations());
+}
+
+#[test]
+fn next_bits_across_words() {
+    let mut bits = BitBuffer::new(TestMock::new(counter(1..u64::MAX)));
+    assert_eq!(1, bits.next_bits(60));
+    // the remaining 4 bits of the first word (all zero) are followed by the low 4 bits of the second
+    assert_eq!(2 << 4, bits.next_bits(8));
+    assert_eq!(60, bits.remaining());
+    assert_eq!(0, bits.next_bits(60));
+    assert_eq!(3, bits.next_bits(64));
+    assert_eq!(0, bits.remaining());
+}
+
+#[test]
+fn next_bits_whole_word_when_partly_cached() {
+    let mut bits = BitBuffer::new(TestMock::new(fixed(u64::MAX)));
+    assert_eq!(1, bits.next_bits(1));
+    assert_eq!(u64::MAX, bits.next_bits(64));
+    assert_eq!(63, bits.remaining());
+    assert_eq!(2, bits.rand_mut().state().next_u64_invocations());
+}
+
+#[test]
+#[should_panic(expected = "n (65) cannot be greater than 64")]
+fn next_bits_panics_over_64() {
+    BitBuffer::new(TestMock::new(fixed(0))).next_bits(65);
+}
+
+#[test]
+fn next_lim_power_of_two() {
+    let mut bits = BitBuffer::new(TestMock::new(fixed(0b1110_0100)));
+    assert_eq!(0, bits.next_lim(1));
+    assert_eq!(0, bits.next_lim(2));
+    assert_eq!(0b10, bits.next_lim(4));
+    assert_eq!(0b1100, bits.next_lim(16));
+    assert_eq!(57, bits.remaining());
+    // the remaining 57 bits (0b1) are followed by the low 6 bits of the next word (0b10_0100)
+    assert_eq!(1 | 0b10_0100 << 57, bits.next_lim(1 << 63));
+    assert_eq!(58, bits.remaining());
+}
+
+#[test]
+fn next_lim_rejects_below_cutoff() {
+    // 3 + 8 bits are drawn for a limit of 6; zero falls below the cutoff of 2^11 mod 6 = 2
+    let mut bits = BitBuffer::new(TestMock::new(fixed(0x7FF << 11)));
+    assert_eq!(5, bits.next_lim(6));
+    assert_eq!(42, bits.remaining());
+}
+
+#[test]
+fn next_lim_lowest_and_highest() {
+    let mut bits = BitBuffer::new(TestMock::new(fixed(1)));
+    assert_eq!(0, bits.next_lim(6));
+    let mut bits = BitBuffer::new(TestMock::new(fixed(u64::MAX)));
+    assert_eq!(5, bits.next_lim(6));
+    assert_eq!((1 << 32) - 2, bits.next_lim((1 << 32) - 1));
+    assert_eq!(64 - 11 - 40, bits.remaining());
+}
+
+#[test]
+fn next_lim_large_uses_whole_words() {
+    let mut bits = BitBuffer::new(TestMock::new(fixed(u64::MAX)));
+    assert!(bits.next_bit());
+    let lim = (1 << 32) + 1;
+    assert_eq!(lim - 1, bits.next_lim(lim));
+    assert_eq!(63, bits.remaining());
+}
+
+#[test]
+#[should_panic(expected = "zero limit")]
+fn next_lim_panics_zero() {
+    BitBuffer::new(TestMock::new(fixed(0))).next_lim(0);
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn next_lim_is_uniform() {
+    const ITERS: usize = 60_000;
+    let mut bits = BitBuffer::new(Wyrand::default());
+    for lim in [3, 6, 10, 16] {
+        let mut counts = [0usize; 16];
+        for _ in 0..ITERS {
+            counts[bits.next_lim(lim) as usize] += 1;
+        }
+        let expected = ITERS as f64 / lim as f64;
+        for (value, &count) in counts.iter().enumerate() {
+            if value < lim as usize {
+                let deviation = (count as f64 - expected).abs() / expected;
+                assert!(deviation < 0.05, "{value} of {lim}: {count} vs {expected}");
+            } else {
+                assert_eq!(0, count);
+            }
+        }
+    }
+}
+
+#[test]
+fn into_inner() {
+    let mut bits = BitBuffer::new(Wyrand::seed(42));
+    bits.next_bit();
+    let mut rand = bits.into_inner();
+    let mut control = Wyrand::seed(42);
+    control.next_u64();
+    assert_eq!(control.next_u64(), rand.next_u64());
+}
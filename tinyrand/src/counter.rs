@@ -1,6 +1,6 @@
 //! A wrap-around 64-bit counter. Useful for seeding and testing.
 
-use crate::{Rand, Seeded};
+use crate::{Rand, SeedableFromBytes, Seeded};
 
 /// A wrap-around counter.
 ///
@@ -39,5 +39,15 @@ impl Seeded for Counter {
     }
 }
 
+impl SeedableFromBytes for Counter {
+    type Seed = [u8; 8];
+
+    /// Equivalent to [`Seeded::seed`], with the seed read as a little-endian `u64`.
+    #[inline(always)]
+    fn from_seed_bytes(seed: Self::Seed) -> Self {
+        Self::seed(u64::from_le_bytes(seed))
+    }
+}
+
 #[cfg(test)]
 mod tests;
\ No newline at end of file
@@ -27,4 +27,9 @@ fn implements_debug() {
     let s = format!("{rand:?}");
     assert!(s.contains("Counter"));
     assert!(s.contains("42"));
-}
\ No newline at end of file
+}
+
+#[test]
+fn seeding_counter() {
+    crate::tests::seeding_u64::<Counter>();
+}
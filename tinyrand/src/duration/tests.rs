@@ -32,7 +32,7 @@ pub fn random_duration(mut rand: impl Rand) {
         exp_min: Duration,
         exp_max: Duration,
     }
-    for case in &vec![
+    for case in &[
         // from zero
         Case {
             range: Duration::ZERO..Duration::from_nanos(100),
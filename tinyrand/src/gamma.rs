@@ -0,0 +1,82 @@
+//! The [gamma distribution](https://en.wikipedia.org/wiki/Gamma_distribution).
+
+use crate::normal::standard_normal;
+use crate::{Distribution, Rand};
+
+/// The gamma distribution, parametrised by its shape (_k_) and scale (_θ_).
+///
+/// Samples are generated using the method of G. Marsaglia and W. Tsang. For shapes less than 1, the
+/// sample is drawn with a shape of _k_ + 1 and scaled by _U_<sup>1/_k_</sup>, where _U_ is uniform.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gamma {
+    shape: f64,
+    scale: f64,
+    d: f64,
+    c: f64,
+}
+
+impl Gamma {
+    /// Creates a new [`Gamma`] distribution with the given shape and scale.
+    ///
+    /// # Panics
+    /// If either `shape` or `scale` is not a positive, finite number.
+    pub fn new(shape: f64, scale: f64) -> Self {
+        assert!(shape > 0f64, "shape ({shape}) must be greater than 0");
+        assert!(shape.is_finite(), "shape ({shape}) must be finite");
+        assert!(scale > 0f64, "scale ({scale}) must be greater than 0");
+        assert!(scale.is_finite(), "scale ({scale}) must be finite");
+        let d = if shape < 1.0 { shape + 1.0 } else { shape } - 1.0 / 3.0;
+        let c = 1.0 / libm::sqrt(9.0 * d);
+        Self { shape, scale, d, c }
+    }
+
+    /// Samples the natural logarithm of a gamma variate.
+    ///
+    /// For small shapes, most of the probability mass is concentrated so close to zero that
+    /// the variates frequently underflow `f64`. Their logarithms, however, are always representable,
+    /// which makes this method useful for computing ratios of gamma variates (e.g., when sampling
+    /// from the Dirichlet distribution).
+    pub fn sample_ln(&self, rand: &mut impl Rand) -> f64 {
+        let ln_standard = if self.shape < 1.0 {
+            // 1 - U is in (0, 1], so its logarithm is always finite
+            libm::log(self.marsaglia_tsang(rand)) + libm::log(1.0 - rand.next_f64()) / self.shape
+        } else {
+            libm::log(self.marsaglia_tsang(rand))
+        };
+        ln_standard + libm::log(self.scale)
+    }
+
+    /// Samples from the standard gamma distribution with a shape of `d + 1/3`.
+    #[inline(always)]
+    fn marsaglia_tsang(&self, rand: &mut impl Rand) -> f64 {
+        loop {
+            let x = standard_normal(rand);
+            let v = 1.0 + self.c * x;
+            if v <= 0.0 {
+                continue;
+            }
+            let v = v * v * v;
+            let u = rand.next_f64();
+            let x_sq = x * x;
+            if u < 1.0 - 0.0331 * x_sq * x_sq
+                || libm::log(u) < 0.5 * x_sq + self.d * (1.0 - v + libm::log(v))
+            {
+                return self.d * v;
+            }
+        }
+    }
+}
+
+impl Distribution<f64> for Gamma {
+    fn sample(&self, rand: &mut impl Rand) -> f64 {
+        let standard = if self.shape < 1.0 {
+            self.marsaglia_tsang(rand) * libm::pow(1.0 - rand.next_f64(), 1.0 / self.shape)
+        } else {
+            self.marsaglia_tsang(rand)
+        };
+        standard * self.scale
+    }
+}
+
+#[cfg(test)]
+mod tests;
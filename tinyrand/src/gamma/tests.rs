@@ -0,0 +1,79 @@
+use crate::gamma::Gamma;
+use crate::{Distribution, Wyrand};
+
+fn assert_moments(shape: f64, scale: f64) {
+    let mut rand = Wyrand::default();
+    let dist = Gamma::new(shape, scale);
+    const N: u32 = 100_000;
+    let (mut sum, mut sum_sq) = (0.0, 0.0);
+    for _ in 0..N {
+        let x = dist.sample(&mut rand);
+        assert!(x >= 0.0, "for shape={shape}, scale={scale}, sampled {x}");
+        sum += x;
+        sum_sq += x * x;
+    }
+    let mean = sum / f64::from(N);
+    let variance = sum_sq / f64::from(N) - mean * mean;
+    let (expected_mean, expected_variance) = (shape * scale, shape * scale * scale);
+    assert!(
+        (mean - expected_mean).abs() < 0.02 * expected_mean,
+        "for shape={shape}, scale={scale}, mean={mean}"
+    );
+    assert!(
+        (variance - expected_variance).abs() < 0.05 * expected_variance,
+        "for shape={shape}, scale={scale}, variance={variance}"
+    );
+}
+
+#[test]
+fn gamma_moments() {
+    assert_moments(1.0, 1.0);
+    assert_moments(2.0, 0.5);
+    assert_moments(9.0, 3.0);
+    assert_moments(0.5, 2.0);
+}
+
+#[test]
+fn sample_ln_of_tiny_shape_is_finite() {
+    let mut rand = Wyrand::default();
+    let dist = Gamma::new(1e-3, 1.0);
+    for _ in 0..1_000 {
+        let ln = dist.sample_ln(&mut rand);
+        assert!(ln.is_finite(), "sampled {ln}");
+    }
+}
+
+#[test]
+fn sample_ln_matches_sample() {
+    let dist = Gamma::new(3.0, 2.0);
+    let (mut rand_a, mut rand_b) = (Wyrand::default(), Wyrand::default());
+    for _ in 0..100 {
+        let x = dist.sample(&mut rand_a);
+        let ln = dist.sample_ln(&mut rand_b);
+        assert!((libm::log(x) - ln).abs() < 1e-9, "x={x}, ln={ln}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "shape (0) must be greater than 0")]
+fn zero_shape() {
+    Gamma::new(0.0, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "shape (inf) must be finite")]
+fn infinite_shape() {
+    Gamma::new(f64::INFINITY, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "scale (-1) must be greater than 0")]
+fn negative_scale() {
+    Gamma::new(1.0, -1.0);
+}
+
+#[test]
+#[should_panic(expected = "scale (NaN) must be greater than 0")]
+fn nan_scale() {
+    Gamma::new(1.0, f64::NAN);
+}
@@ -0,0 +1,67 @@
+//! Multi-lane generators, which produce blocks of random numbers at a time.
+//!
+//! A multi-lane generator runs `L` independent instances (lanes) of an RNG in lockstep, keeping
+//! their states in plain arrays so that the compiler may vectorise the updates. No nightly features
+//! or platform intrinsics are used; how much of the work is vectorised depends on the target and
+//! the algorithm. [`XoshiroLanes`](crate::XoshiroLanes) uses only shifts, rotations, additions and
+//! XORs, and is several times faster than its scalar counterpart when built for a target with
+//! wide vector registers (e.g., with `-C target-cpu=native` on a CPU with AVX2); on baseline x86-64,
+//! it may be slower. [`WyrandLanes`](crate::WyrandLanes) relies on a 64×64-bit widening multiply,
+//! which few targets vectorise, so it gains little beyond breaking the dependency between
+//! successive outputs.
+//!
+//! # Lane interleaving
+//! Element `i` of each block is the next output of lane `i`. [`RandBlock::fill_u64`] writes
+//! successive blocks, so that element `k` of the output is the output of lane `k % L` at step
+//! `k / L` (counting from the generator's position at the time of the call). If the length of
+//! the output is not a multiple of `L`, the trailing block is generated in full and its unused
+//! elements are discarded; each call therefore begins on a block boundary, and the output depends
+//! only on the seed and the lengths of the preceding calls — not on the target's vector width.
+
+use crate::{Rand, Seeded, SplitMix};
+
+/// An RNG that generates random numbers in blocks of `L`.
+pub trait RandBlock<const L: usize> {
+    /// Returns the next block of random `u64`s, one from each lane.
+    fn next_block(&mut self) -> [u64; L];
+
+    /// Fills the given slice with random `u64`s, interleaving the lanes.
+    #[inline(always)]
+    fn fill_u64(&mut self, out: &mut [u64]) {
+        let mut chunks = out.chunks_exact_mut(L);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next_block());
+        }
+        let remainder = chunks.into_remainder();
+        if !remainder.is_empty() {
+            let block = self.next_block();
+            remainder.copy_from_slice(&block[..remainder.len()]);
+        }
+    }
+
+    /// Fills the given slice with random `f64`s in the range \[0, 1), interleaving the lanes
+    /// in the same way as [`RandBlock::fill_u64`]. Each `u64` is converted as by [`Rand::next_f64`].
+    #[inline(always)]
+    #[allow(clippy::cast_precision_loss)]
+    fn fill_f64(&mut self, out: &mut [f64]) {
+        let mut chunks = out.chunks_mut(L);
+        for chunk in &mut chunks {
+            let block = self.next_block();
+            for (value, &random) in chunk.iter_mut().zip(block.iter()) {
+                *value = (random >> 11) as f64 * (1.0 / (1u64 << 53) as f64);
+            }
+        }
+    }
+}
+
+/// Derives the seeds of `L` lanes from a single seed, these being the first `L` outputs of
+/// [`SplitMix`] seeded with `seed`.
+#[inline(always)]
+pub(crate) fn seed_lanes<const L: usize>(seed: u64) -> [u64; L] {
+    assert_ne!(0, L, "zero lanes");
+    let mut splitmix = SplitMix::seed(seed);
+    [(); L].map(|()| splitmix.next_u64())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,64 @@
+use crate::lanes::{seed_lanes, RandBlock};
+use crate::{Rand, Seeded, SplitMix, Wyrand, WyrandLanes, XoshiroLanes};
+
+#[test]
+fn seed_lanes_from_splitmix() {
+    let mut splitmix = SplitMix::seed(7);
+    let expected = [(); 5].map(|_| splitmix.next_u64());
+    assert_eq!(expected, seed_lanes::<5>(7));
+}
+
+#[test]
+#[should_panic(expected = "zero lanes")]
+fn seed_lanes_panics_zero() {
+    seed_lanes::<0>(0);
+}
+
+#[test]
+fn fill_u64_interleaves_lanes() {
+    let mut lanes = WyrandLanes::from_seeds([10, 20, 30]);
+    let mut scalars = [10, 20, 30].map(Wyrand::seed);
+    let mut out = [0; 12];
+    lanes.fill_u64(&mut out);
+    for (k, &random) in out.iter().enumerate() {
+        assert_eq!(scalars[k % 3].next_u64(), random, "element {k}");
+    }
+}
+
+#[test]
+fn fill_u64_discards_partial_block() {
+    let mut lanes = XoshiroLanes::<4>::seed(0);
+    let mut control = lanes.clone();
+    let mut out = [0; 6];
+    lanes.fill_u64(&mut out);
+    let (first, second) = (control.next_block(), control.next_block());
+    assert_eq!(first, out[..4]);
+    assert_eq!(second[..2], out[4..]);
+
+    // the next call begins on a block boundary
+    lanes.fill_u64(&mut out[..1]);
+    assert_eq!(control.next_block()[0], out[0]);
+    assert_eq!(control, lanes);
+}
+
+#[test]
+fn fill_u64_empty() {
+    let mut lanes = XoshiroLanes::<8>::seed(0);
+    let control = lanes.clone();
+    lanes.fill_u64(&mut []);
+    assert_eq!(control, lanes);
+}
+
+#[test]
+fn fill_f64_matches_next_f64() {
+    let mut lanes = WyrandLanes::from_seeds([1, 2]);
+    let mut scalars = [1, 2].map(Wyrand::seed);
+    let mut out = [0.0; 5];
+    lanes.fill_f64(&mut out);
+    for (k, &random) in out.iter().enumerate() {
+        assert_eq!(scalars[k % 2].next_f64(), random, "element {k}");
+    }
+    // the unused element of the trailing block is discarded
+    scalars[1].next_u64();
+    assert_eq!([scalars[0].next_u64(), scalars[1].next_u64()], lanes.next_block());
+}
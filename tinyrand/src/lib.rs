@@ -3,18 +3,49 @@
 
 #![no_std]
 
+pub mod bernoulli;
+pub mod bit_buffer;
+pub(crate) mod binomial;
 pub mod counter;
 pub mod duration;
+pub(crate) mod gamma;
+pub mod lanes;
 pub mod mock_support;
+pub mod multivariate;
+pub(crate) mod normal;
+pub mod reservoir;
+pub mod scramble;
+pub mod seed_sequence;
+pub mod seed_source;
+pub mod slice;
 pub mod splitmix;
+pub mod uniform;
 pub mod xorshift;
+pub mod wyhash;
 pub mod wyrand;
+pub mod xoshiro;
+pub mod zipf;
 
+pub use bernoulli::Bernoulli;
+pub use bit_buffer::BitBuffer;
 pub use counter::Counter;
+pub use lanes::RandBlock;
+pub use multivariate::{Dirichlet, Multinomial, UnitBall, UnitCircle, UnitHypersphere, UnitSphere};
+pub use reservoir::RandIter;
+pub use seed_sequence::SeedSequence;
+pub use seed_source::{FixedSeed, SeedSource};
+pub use slice::RandSlice;
 pub use splitmix::SplitMix;
-pub use wyrand::Wyrand;
+pub use uniform::{SampleUniform, Uniform};
+pub use wyrand::{Wyrand, WyrandLanes};
 pub use xorshift::Xorshift;
+pub use xoshiro::{Xoshiro, XoshiroLanes};
 pub use mock_support::RefCellExt;
+pub use zipf::{PowerLaw, Zipf};
+
+/// Requires the `macros` feature.
+#[cfg(feature = "macros")]
+pub use tinyrand_macros::test;
 
 use core::ops::Range;
 
@@ -74,8 +105,41 @@ pub trait Rand {
         self.next_u128() as usize
     }
 
+    /// Returns the next random `f32` in the range \[0, 1).
+    ///
+    /// The result is taken from the upper 24 bits of a random `u32`, so that every value is an integer
+    /// multiple of 2<sup>-24</sup> and all values are equally likely.
+    #[inline(always)]
+    #[allow(clippy::cast_precision_loss)]
+    fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 * (1.0 / (1u32 << 24) as f32)
+    }
+
+    /// Returns the next random `f64` in the range \[0, 1).
+    ///
+    /// The result is taken from the upper 53 bits of a random `u64`, so that every value is an integer
+    /// multiple of 2<sup>-53</sup> and all values are equally likely.
+    ///
+    /// # Example
+    /// ```
+    /// use tinyrand::{StdRand, Rand};
+    /// let mut rng = StdRand::default();
+    /// let f = rng.next_f64();
+    /// assert!((0.0..1.0).contains(&f));
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::cast_precision_loss)]
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
     /// Returns a `bool` with a probability `p` of being true.
     ///
+    /// A probability created from a ratio is sampled exactly, using [`Rand::next_lim_u64`]. Otherwise,
+    /// `p` is scaled to a `u64` cutoff, which is compared with a random `u64`. (See [`Probability::new`]
+    /// for the bias of this method.) When sampling repeatedly with the same probability, [`Bernoulli`]
+    /// saves recomputing the cutoff.
+    ///
     /// # Example
     /// ```
     /// use tinyrand::{StdRand, Probability, Rand};
@@ -84,15 +148,7 @@ pub trait Rand {
     /// ```
     #[inline(always)]
     fn next_bool(&mut self, p: Probability) -> bool {
-        #[allow(clippy::cast_precision_loss)]
-        #[allow(clippy::cast_sign_loss)]
-        let cutoff = (p.0 * u64::MAX as f64) as u64;
-        let mut next = self.next_u64();
-        if next == u64::MAX {
-            // guarantees that gen_bool(p=1.0) is never true
-            next = u64::MAX - 1;
-        }
-        next < cutoff
+        Bernoulli::new(p).sample_unsized(self)
     }
 
     /// Generates a random number in `0..lim`.
@@ -144,8 +200,34 @@ pub trait Rand {
     }
 
     /// Generates a random number in `0..lim`.
+    ///
+    /// Limits that fit in a `u64` are served by [`Rand::next_lim_u64`]. Larger limits use
+    /// the same method, but with a 256-bit product of a random `u128` and the limit.
     #[inline(always)]
     fn next_lim_u128(&mut self, lim: u128) -> u128 {
+        assert_ne!(0, lim, "zero limit");
+        if lim <= u128::from(u64::MAX) {
+            u128::from(self.next_lim_u64(lim as u64))
+        } else {
+            let (mut high, mut low) = wide_mul_u128(self.next_u128(), lim);
+            if low < lim {
+                let cutoff = lim.wrapping_neg() % lim;
+                while low < cutoff {
+                    (high, low) = wide_mul_u128(self.next_u128(), lim);
+                }
+            }
+            high
+        }
+    }
+
+    /// Generates a random number in `0..lim`, using the method of `tinyrand` 0.5 and earlier.
+    ///
+    /// For limits that exceed `u64::MAX`, [`Rand::next_lim_u128`] was previously implemented
+    /// with a modulo-based rejection loop, which is unbiased but considerably slower. This method
+    /// is retained for applications that depend on the sequences it produces; it is otherwise
+    /// best avoided.
+    #[inline(always)]
+    fn next_lim_u128_legacy(&mut self, lim: u128) -> u128 {
         assert_ne!(0, lim, "zero limit");
         if lim <= u128::from(u64::MAX) {
             u128::from(self.next_lim_u64(lim as u64))
@@ -190,12 +272,28 @@ pub trait Rand {
 }
 
 /// Represents a probability in the range \[0, 1\].
+///
+/// A probability is either a floating-point value, created with [`Probability::new`], or
+/// an exact ratio of two integers, created with [`Probability::from_ratio`].
 #[derive(Clone, Copy, PartialEq, Debug)]
-pub struct Probability(f64);
+pub struct Probability(Repr);
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Repr {
+    Float(f64),
+    Ratio { numerator: u64, denominator: u64 },
+}
 
 impl Probability {
     /// Creates a new [`Probability`] value, bounded in the range \[0, 1\].
     ///
+    /// When sampled, `p` is scaled by 2<sup>64</sup> and truncated to a `u64` cutoff, against which
+    /// a random `u64` is compared. Since the scaling is exact, the probability of a `true` outcome
+    /// is ⌊`p` × 2<sup>64</sup>⌋ / 2<sup>64</sup>, undershooting `p` by less than 2<sup>-64</sup>
+    /// (and being exact for 0 and 1). The larger error is in `p` itself: a fraction like 1/3
+    /// is not representable as an `f64`, and is rounded by up to 2<sup>-54</sup>. Where this matters,
+    /// use [`Probability::from_ratio`].
+    ///
     /// # Example
     /// ```
     /// use tinyrand::Probability;
@@ -209,7 +307,7 @@ impl Probability {
     pub fn new(p: f64) -> Self {
         assert!(p >= 0f64, "p ({p}) cannot be less than 0");
         assert!(p <= 1f64, "p ({p}) cannot be greater than 1");
-        Self(p)
+        Self(Repr::Float(p))
     }
 
     /// Creates a new [`Probability`] value, without checking the bounds.
@@ -219,14 +317,64 @@ impl Probability {
     /// RNG is undefined.
     #[inline(always)]
     pub const unsafe fn new_unchecked(p: f64) -> Self {
-        Self(p)
+        Self(Repr::Float(p))
+    }
+
+    /// Creates a new [`Probability`] value from the ratio `numerator / denominator`, which is
+    /// sampled exactly. The ratio is reduced to its lowest terms, so that equal ratios produce
+    /// equal probabilities.
+    ///
+    /// # Example
+    /// ```
+    /// use tinyrand::Probability;
+    /// let p = Probability::from_ratio(2, 6);
+    /// assert_eq!(Probability::from_ratio(1, 3), p);
+    /// assert_eq!(Some((1, 3)), p.ratio());
+    /// ```
+    ///
+    /// # Panics
+    /// If `denominator` is zero, or if `numerator > denominator`.
+    #[inline(always)]
+    pub fn from_ratio(numerator: u64, denominator: u64) -> Self {
+        assert_ne!(0, denominator, "zero denominator");
+        assert!(
+            numerator <= denominator,
+            "numerator ({numerator}) cannot be greater than denominator ({denominator})"
+        );
+        let divisor = gcd(numerator, denominator);
+        Self(Repr::Ratio {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        })
+    }
+
+    /// The numerator and denominator of this probability, if it was created from a ratio.
+    #[inline(always)]
+    pub fn ratio(&self) -> Option<(u64, u64)> {
+        match self.0 {
+            Repr::Float(_) => None,
+            Repr::Ratio { numerator, denominator } => Some((numerator, denominator)),
+        }
     }
 }
 
+/// The greatest common divisor of `a` and `b`, where `b` is nonzero.
+#[inline(always)]
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while a != 0 {
+        (a, b) = (b % a, a);
+    }
+    b
+}
+
 impl From<Probability> for f64 {
     #[inline(always)]
+    #[allow(clippy::cast_precision_loss)]
     fn from(p: Probability) -> Self {
-        p.0
+        match p.0 {
+            Repr::Float(p) => p,
+            Repr::Ratio { numerator, denominator } => numerator as f64 / denominator as f64,
+        }
     }
 }
 
@@ -243,6 +391,122 @@ pub trait Seeded {
 
     /// Creates a new [`Rand`] instance from the given seed.
     fn seed(seed: u64) -> Self::R;
+
+    /// Creates a new [`Rand`] instance from the given seed, after [scrambling](scramble::scramble)
+    /// it. Nearby seeds — such as loop indices — thus yield unrelated streams, even for generators
+    /// that use the seed directly as state.
+    ///
+    /// The scrambler is frozen, so a given seed always yields the same generator; however, that
+    /// generator differs from the one returned by [`Seeded::seed`].
+    ///
+    /// # Examples
+    /// ```
+    /// use tinyrand::{Rand, Seeded, StdRand};
+    /// for run in 0..4 {
+    ///     let mut rand = StdRand::seed_scrambled(run);
+    ///     println!("run {run}: {}", rand.next_u64());
+    /// }
+    /// ```
+    #[inline(always)]
+    fn seed_scrambled(seed: u64) -> Self::R {
+        Self::seed(scramble::scramble(seed))
+    }
+
+    /// Creates a new [`Rand`] instance from a 128-bit seed.
+    ///
+    /// For generators with 64 bits of state, the seed is folded to 64 bits by `XOR`ing its lower
+    /// half with the first output of [`SplitMix`] seeded with its upper half (if nonzero). Thus,
+    /// seeds below 2<sup>64</sup> are equivalent to those passed to [`Seeded::seed`], and seeds
+    /// differing only in their lower halves never collide. Generators with more state, such as
+    /// [`Xoshiro`], use all 128 bits.
+    #[inline(always)]
+    fn seed_u128(seed: u128) -> Self::R {
+        let (low, high) = (seed as u64, (seed >> 64) as u64);
+        let offset = if high == 0 { 0 } else { SplitMix::seed(high).next_u64() };
+        Self::seed(low ^ offset)
+    }
+
+    /// Creates a new [`Rand`] instance from a string, such as the name of a test.
+    ///
+    /// Equivalent to [`Seeded::seed_from_bytes`] over the string's UTF-8 encoding.
+    ///
+    /// # Examples
+    /// ```
+    /// use tinyrand::{Rand, Seeded, StdRand};
+    /// let mut rand = StdRand::seed_from_str("order_book_fuzz");
+    /// println!("{}", rand.next_u64());
+    /// ```
+    #[inline(always)]
+    fn seed_from_str(seed: &str) -> Self::R {
+        Self::seed_from_bytes(seed.as_bytes())
+    }
+
+    /// Creates a new [`Rand`] instance from a byte string of any length, by seeding with its
+    /// hash. (Compare with [`SeedableFromBytes`], which takes a seed as large as the state.)
+    ///
+    /// The hash is [`wyhash`](wyhash::wyhash) final 4 with a seed of zero. It is the same on
+    /// every platform, and will not change in future versions of this crate; hence, a given
+    /// byte string always seeds a given generator identically.
+    #[inline(always)]
+    fn seed_from_bytes(seed: &[u8]) -> Self::R {
+        Self::seed(wyhash::wyhash(seed, 0))
+    }
+
+    /// Creates a new [`Rand`] instance, drawing its seed from another [`Rand`].
+    ///
+    /// For generators with 64 bits of state, this is equivalent to seeding with the next `u64`.
+    /// Generators with more state draw as many words as they need.
+    #[inline(always)]
+    fn from_rand<G: Rand + ?Sized>(rand: &mut G) -> Self::R {
+        Self::seed(rand.next_u64())
+    }
+
+    /// Creates a new [`Rand`] instance, seeded from the given [`SeedSource`].
+    ///
+    /// # Errors
+    /// If the source is unable to produce a seed.
+    #[inline(always)]
+    fn from_source<S: SeedSource + ?Sized>(source: &mut S) -> Result<Self::R, S::Error> {
+        source.next_seed().map(Self::seed)
+    }
+}
+
+/// The means for seeding an RNG with as many bytes as it has state.
+///
+/// A `u64` seed cannot reach every state of a generator with more than 64 bits of state,
+/// and makes collisions likely once there are billions of seeds (by the birthday bound, around
+/// 2<sup>32</sup>). A [`SeedableFromBytes`] generator accepts a seed as large as its state.
+pub trait SeedableFromBytes: Sized {
+    /// The seed, a byte array of the same size as the generator's state.
+    type Seed: Sized + Default + AsRef<[u8]> + AsMut<[u8]>;
+
+    /// Creates a new instance from the given seed. The bytes are read as little-endian words.
+    fn from_seed_bytes(seed: Self::Seed) -> Self;
+}
+
+/// A probability distribution from which values of type `T` can be sampled.
+///
+/// Distributions are immutable once constructed; any state they need for sampling (e.g., normalisation
+/// constants) is computed upfront, so that sampling is cheap.
+pub trait Distribution<T> {
+    /// Samples a value from the distribution, using the given [`Rand`] as the source of randomness.
+    fn sample(&self, rand: &mut impl Rand) -> T;
+}
+
+/// Multiplies two `u128`s, returning the high and low halves of the 256-bit product.
+#[inline(always)]
+fn wide_mul_u128(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_high, a_low) = ((a >> 64) as u64, a as u64);
+    let (b_high, b_low) = ((b >> 64) as u64, b as u64);
+    let low_low = u128::from(a_low) * u128::from(b_low);
+    let low_high = u128::from(a_low) * u128::from(b_high);
+    let high_low = u128::from(a_high) * u128::from(b_low);
+    let high_high = u128::from(a_high) * u128::from(b_high);
+    let mid = (low_low >> 64) + (low_high & MASK) + (high_low & MASK);
+    let high = high_high + (low_high >> 64) + (high_low >> 64) + (mid >> 64);
+    let low = mid << 64 | low_low & MASK;
+    (high, low)
 }
 
 #[inline(always)]
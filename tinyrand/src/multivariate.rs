@@ -0,0 +1,305 @@
+//! Multivariate distributions over fixed-size arrays.
+//!
+//! The distributions here do not allocate; their dimensions are fixed at compile time. For each
+//! distribution, there is also a `fill_` function that samples into a caller-supplied slice,
+//! for when the dimensions are only known at runtime. (See `tinyrand-alloc` for `Vec`-based variants.)
+
+use crate::binomial::Binomial;
+use crate::gamma::Gamma;
+use crate::normal::standard_normal;
+use crate::{Distribution, Rand};
+use core::f64::consts::PI;
+
+/// The [Dirichlet distribution](https://en.wikipedia.org/wiki/Dirichlet_distribution) of order `N`,
+/// whose samples are points on the (`N` - 1)-simplex. I.e., the sampled components are non-negative
+/// and sum to 1.
+///
+/// # Examples
+/// ```
+/// use tinyrand::{Dirichlet, Distribution, StdRand};
+/// let dist = Dirichlet::new([1.0, 2.0, 0.5]);
+/// let mut rand = StdRand::default();
+/// let sample = dist.sample(&mut rand);
+/// assert!((sample.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dirichlet<const N: usize> {
+    gammas: [Gamma; N],
+}
+
+impl<const N: usize> Dirichlet<N> {
+    /// Creates a new [`Dirichlet`] distribution with the given concentration parameters.
+    ///
+    /// # Panics
+    /// If `N` is zero, or if any of the parameters is not a positive, finite number.
+    pub fn new(alpha: [f64; N]) -> Self {
+        assert_ne!(0, N, "no concentration parameters");
+        Self {
+            gammas: alpha.map(|alpha| Gamma::new(alpha, 1.0)),
+        }
+    }
+}
+
+impl<const N: usize> Distribution<[f64; N]> for Dirichlet<N> {
+    fn sample(&self, rand: &mut impl Rand) -> [f64; N] {
+        let mut sample = [0.0; N];
+        for (gamma, component) in self.gammas.iter().zip(sample.iter_mut()) {
+            *component = gamma.sample_ln(rand);
+        }
+        normalise_ln(&mut sample);
+        sample
+    }
+}
+
+/// Samples from the Dirichlet distribution with the concentration parameters `alpha`, writing the
+/// components into `out`.
+///
+/// # Panics
+/// If `alpha` is empty, if it differs in length from `out`, or if any of its elements is not
+/// a positive, finite number.
+pub fn fill_dirichlet(rand: &mut impl Rand, alpha: &[f64], out: &mut [f64]) {
+    assert!(!alpha.is_empty(), "no concentration parameters");
+    assert_eq!(alpha.len(), out.len(), "mismatched dimensions");
+    for (&alpha, component) in alpha.iter().zip(out.iter_mut()) {
+        *component = Gamma::new(alpha, 1.0).sample_ln(rand);
+    }
+    normalise_ln(out);
+}
+
+/// Maps the logarithms of gamma variates to their normalised ratios, subtracting the largest
+/// logarithm before exponentiation to avoid underflow.
+fn normalise_ln(components: &mut [f64]) {
+    let max = components.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let mut sum = 0.0;
+    for component in components.iter_mut() {
+        *component = libm::exp(*component - max);
+        sum += *component;
+    }
+    for component in components.iter_mut() {
+        *component /= sum;
+    }
+}
+
+/// The [multinomial distribution](https://en.wikipedia.org/wiki/Multinomial_distribution), being the
+/// number of times each of `K` categories is chosen in `n` independent trials.
+///
+/// Samples are generated by the conditional binomial method, at a cost of `K` binomial samples,
+/// irrespective of `n`.
+///
+/// # Examples
+/// ```
+/// use tinyrand::{Distribution, Multinomial, StdRand};
+/// let dist = Multinomial::new(100, [0.2, 0.3, 0.5]);
+/// let mut rand = StdRand::default();
+/// let counts = dist.sample(&mut rand);
+/// assert_eq!(100, counts.iter().sum::<u64>());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Multinomial<const K: usize> {
+    n: u64,
+    conditional: [f64; K],
+}
+
+impl<const K: usize> Multinomial<K> {
+    /// Creates a new [`Multinomial`] distribution over `n` trials. The categories are chosen with
+    /// probabilities proportional to the given `weights`, which need not sum to 1.
+    ///
+    /// # Panics
+    /// If `K` is zero, if any of the weights is negative or not finite, or if all weights are zero.
+    pub fn new(n: u64, weights: [f64; K]) -> Self {
+        let mut conditional = weights;
+        conditional_probabilities(&mut conditional);
+        Self { n, conditional }
+    }
+
+    /// The number of trials.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+}
+
+impl<const K: usize> Distribution<[u64; K]> for Multinomial<K> {
+    fn sample(&self, rand: &mut impl Rand) -> [u64; K] {
+        let mut counts = [0; K];
+        conditional_binomials(rand, self.n, &self.conditional, &mut counts);
+        counts
+    }
+}
+
+/// Samples from the multinomial distribution over `n` trials, where the categories are chosen
+/// with probabilities proportional to the given `weights`, writing the counts into `out`.
+///
+/// # Panics
+/// If `weights` is empty, if it differs in length from `out`, if any of its elements is negative or
+/// not finite, or if all of its elements are zero.
+pub fn fill_multinomial(rand: &mut impl Rand, n: u64, weights: &[f64], out: &mut [u64]) {
+    assert_eq!(weights.len(), out.len(), "mismatched dimensions");
+    let mut sum = 0.0;
+    for &weight in weights {
+        validate_weight(weight);
+        sum += weight;
+    }
+    assert!(sum > 0.0, "all weights are zero");
+
+    let mut remaining_trials = n;
+    let mut remaining_weight = sum;
+    for (&weight, count) in weights.iter().zip(out.iter_mut()) {
+        *count = sample_conditional(rand, remaining_trials, weight, remaining_weight);
+        remaining_trials -= *count;
+        remaining_weight -= weight;
+    }
+    // rounding errors in the running weight may leave a handful of trials unassigned
+    if remaining_trials > 0 {
+        let last = weights.iter().rposition(|&weight| weight > 0.0).unwrap();
+        out[last] += remaining_trials;
+    }
+}
+
+/// Converts weights in-place to the probability of each category, conditional on none of
+/// the preceding categories being chosen.
+///
+/// # Panics
+/// If `weights` is empty, if any of its elements is negative or not finite, or if all of its
+/// elements are zero.
+fn conditional_probabilities(weights: &mut [f64]) {
+    assert!(!weights.is_empty(), "no weights");
+    let mut suffix_sum = 0.0;
+    for weight in weights.iter_mut().rev() {
+        validate_weight(*weight);
+        suffix_sum += *weight;
+        *weight = if suffix_sum > 0.0 { *weight / suffix_sum } else { 0.0 };
+    }
+    assert!(suffix_sum > 0.0, "all weights are zero");
+}
+
+/// Samples each count from a binomial distribution over the trials not yet assigned to the
+/// preceding categories.
+fn conditional_binomials(rand: &mut impl Rand, n: u64, conditional: &[f64], out: &mut [u64]) {
+    let mut remaining_trials = n;
+    for (&p, count) in conditional.iter().zip(out.iter_mut()) {
+        *count = Binomial::new(remaining_trials, p.min(1.0)).sample(rand);
+        remaining_trials -= *count;
+    }
+}
+
+#[inline(always)]
+fn sample_conditional(rand: &mut impl Rand, trials: u64, weight: f64, remaining_weight: f64) -> u64 {
+    if trials == 0 || weight == 0.0 {
+        0
+    } else {
+        Binomial::new(trials, (weight / remaining_weight).min(1.0)).sample(rand)
+    }
+}
+
+#[inline(always)]
+fn validate_weight(weight: f64) {
+    assert!(weight >= 0.0, "weight ({weight}) cannot be less than 0");
+    assert!(weight.is_finite(), "weight ({weight}) must be finite");
+}
+
+/// The uniform distribution of points on the unit circle.
+///
+/// # Examples
+/// ```
+/// use tinyrand::{Distribution, StdRand, UnitCircle};
+/// let mut rand = StdRand::default();
+/// let [x, y] = UnitCircle.sample(&mut rand);
+/// assert!((x * x + y * y - 1.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UnitCircle;
+
+impl Distribution<[f64; 2]> for UnitCircle {
+    fn sample(&self, rand: &mut impl Rand) -> [f64; 2] {
+        let (sin, cos) = libm::sincos(2.0 * PI * rand.next_f64());
+        [cos, sin]
+    }
+}
+
+/// The uniform distribution of points on the surface of the unit sphere in three dimensions.
+///
+/// Samples are generated using Marsaglia's method, which requires neither trigonometric
+/// functions nor normal variates.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UnitSphere;
+
+impl Distribution<[f64; 3]> for UnitSphere {
+    fn sample(&self, rand: &mut impl Rand) -> [f64; 3] {
+        loop {
+            let u = 2.0 * rand.next_f64() - 1.0;
+            let v = 2.0 * rand.next_f64() - 1.0;
+            let s = u * u + v * v;
+            if s < 1.0 {
+                let factor = 2.0 * libm::sqrt(1.0 - s);
+                return [u * factor, v * factor, 1.0 - 2.0 * s];
+            }
+        }
+    }
+}
+
+/// The uniform distribution of points on the surface of the unit hypersphere in `N` dimensions.
+///
+/// Samples are generated by normalising a vector of `N` independent normal variates.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UnitHypersphere<const N: usize>;
+
+impl<const N: usize> Distribution<[f64; N]> for UnitHypersphere<N> {
+    fn sample(&self, rand: &mut impl Rand) -> [f64; N] {
+        let mut point = [0.0; N];
+        fill_unit_hypersphere(rand, &mut point);
+        point
+    }
+}
+
+/// Samples a point on the surface of the unit hypersphere, whose dimension is the length of `out`.
+///
+/// # Panics
+/// If `out` is empty.
+pub fn fill_unit_hypersphere(rand: &mut impl Rand, out: &mut [f64]) {
+    assert!(!out.is_empty(), "zero dimensions");
+    loop {
+        let mut norm_sq = 0.0;
+        for component in out.iter_mut() {
+            *component = standard_normal(rand);
+            norm_sq += *component * *component;
+        }
+        if norm_sq > 0.0 {
+            let norm = libm::sqrt(norm_sq);
+            for component in out.iter_mut() {
+                *component /= norm;
+            }
+            return;
+        }
+    }
+}
+
+/// The uniform distribution of points inside the unit ball in `N` dimensions.
+///
+/// Samples are generated by scaling a point on the unit hypersphere by _U_<sup>1/`N`</sup>,
+/// where _U_ is uniform.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UnitBall<const N: usize>;
+
+impl<const N: usize> Distribution<[f64; N]> for UnitBall<N> {
+    fn sample(&self, rand: &mut impl Rand) -> [f64; N] {
+        let mut point = [0.0; N];
+        fill_unit_ball(rand, &mut point);
+        point
+    }
+}
+
+/// Samples a point inside the unit ball, whose dimension is the length of `out`.
+///
+/// # Panics
+/// If `out` is empty.
+#[allow(clippy::cast_precision_loss)]
+pub fn fill_unit_ball(rand: &mut impl Rand, out: &mut [f64]) {
+    fill_unit_hypersphere(rand, out);
+    let radius = libm::pow(rand.next_f64(), 1.0 / out.len() as f64);
+    for component in out.iter_mut() {
+        *component *= radius;
+    }
+}
+
+#[cfg(test)]
+mod tests;
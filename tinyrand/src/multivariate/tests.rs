@@ -0,0 +1,187 @@
+use crate::multivariate::{
+    fill_dirichlet, fill_multinomial, fill_unit_ball, fill_unit_hypersphere, Dirichlet, Multinomial, UnitBall,
+    UnitCircle, UnitHypersphere, UnitSphere,
+};
+use crate::{Distribution, Wyrand};
+
+fn norm(point: &[f64]) -> f64 {
+    libm::sqrt(point.iter().map(|x| x * x).sum())
+}
+
+#[test]
+fn dirichlet_on_simplex() {
+    let mut rand = Wyrand::default();
+    let dist = Dirichlet::new([0.001, 1.0, 2.0, 50.0]);
+    for _ in 0..1_000 {
+        let sample = dist.sample(&mut rand);
+        assert!(sample.iter().all(|&x| (0.0..=1.0).contains(&x)), "sampled {sample:?}");
+        assert!((sample.iter().sum::<f64>() - 1.0).abs() < 1e-9, "sampled {sample:?}");
+    }
+}
+
+#[test]
+fn dirichlet_means() {
+    let mut rand = Wyrand::default();
+    let alpha = [1.0, 2.0, 7.0];
+    let dist = Dirichlet::new(alpha);
+    const N: u32 = 50_000;
+    let mut sums = [0.0; 3];
+    for _ in 0..N {
+        for (sum, x) in sums.iter_mut().zip(dist.sample(&mut rand)) {
+            *sum += x;
+        }
+    }
+    for (sum, alpha) in sums.iter().zip(alpha) {
+        let mean = sum / f64::from(N);
+        assert!((mean - alpha / 10.0).abs() < 0.005, "mean={mean}, alpha={alpha}");
+    }
+}
+
+#[test]
+fn fill_dirichlet_on_simplex() {
+    let mut rand = Wyrand::default();
+    let mut out = [0.0; 5];
+    fill_dirichlet(&mut rand, &[0.5; 5], &mut out);
+    assert!((out.iter().sum::<f64>() - 1.0).abs() < 1e-9, "sampled {out:?}");
+}
+
+#[test]
+#[should_panic(expected = "no concentration parameters")]
+fn dirichlet_empty() {
+    Dirichlet::new([]);
+}
+
+#[test]
+#[should_panic(expected = "mismatched dimensions")]
+fn fill_dirichlet_mismatched() {
+    fill_dirichlet(&mut Wyrand::default(), &[1.0; 3], &mut [0.0; 2]);
+}
+
+#[test]
+fn multinomial_sums_to_n() {
+    let mut rand = Wyrand::default();
+    for n in [0, 1, 10, 1_000, u64::MAX] {
+        let dist = Multinomial::new(n, [0.0, 1.0, 3.0, 0.0, 2.0, 0.0]);
+        assert_eq!(n, dist.n());
+        for _ in 0..100 {
+            let counts = dist.sample(&mut rand);
+            assert_eq!(n, counts.iter().sum::<u64>(), "sampled {counts:?}");
+            assert_eq!(0, counts[0]);
+            assert_eq!(0, counts[3]);
+            assert_eq!(0, counts[5]);
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn multinomial_means() {
+    let mut rand = Wyrand::default();
+    let dist = Multinomial::new(100, [1.0, 2.0, 7.0]);
+    const N: u32 = 10_000;
+    let mut sums = [0u64; 3];
+    for _ in 0..N {
+        for (sum, count) in sums.iter_mut().zip(dist.sample(&mut rand)) {
+            *sum += count;
+        }
+    }
+    for (sum, expected) in sums.iter().zip([10.0, 20.0, 70.0]) {
+        let mean = *sum as f64 / f64::from(N);
+        assert!((mean - expected).abs() < 0.5, "mean={mean}, expected={expected}");
+    }
+}
+
+#[test]
+fn fill_multinomial_sums_to_n() {
+    let mut rand = Wyrand::default();
+    let weights = [0.1, 0.0, 0.7, 0.2, 0.0];
+    let mut out = [0; 5];
+    for _ in 0..100 {
+        fill_multinomial(&mut rand, 1_000_000, &weights, &mut out);
+        assert_eq!(1_000_000, out.iter().sum::<u64>(), "sampled {out:?}");
+        assert_eq!(0, out[1]);
+        assert_eq!(0, out[4]);
+    }
+}
+
+#[test]
+#[should_panic(expected = "all weights are zero")]
+fn multinomial_zero_weights() {
+    Multinomial::new(10, [0.0, 0.0]);
+}
+
+#[test]
+#[should_panic(expected = "weight (-1) cannot be less than 0")]
+fn multinomial_negative_weight() {
+    Multinomial::new(10, [1.0, -1.0]);
+}
+
+#[test]
+#[should_panic(expected = "weight (NaN) cannot be less than 0")]
+fn fill_multinomial_nan_weight() {
+    fill_multinomial(&mut Wyrand::default(), 10, &[1.0, f64::NAN], &mut [0; 2]);
+}
+
+#[test]
+#[should_panic(expected = "all weights are zero")]
+fn fill_multinomial_empty() {
+    fill_multinomial(&mut Wyrand::default(), 10, &[], &mut []);
+}
+
+#[test]
+fn points_on_unit_surfaces() {
+    let mut rand = Wyrand::default();
+    for _ in 0..1_000 {
+        assert!((norm(&UnitCircle.sample(&mut rand)) - 1.0).abs() < 1e-9);
+        assert!((norm(&UnitSphere.sample(&mut rand)) - 1.0).abs() < 1e-9);
+        assert!((norm(&UnitHypersphere::<1>.sample(&mut rand)) - 1.0).abs() < 1e-9);
+        assert!((norm(&UnitHypersphere::<7>.sample(&mut rand)) - 1.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn points_inside_unit_ball() {
+    let mut rand = Wyrand::default();
+    for _ in 0..1_000 {
+        assert!(norm(&UnitBall::<2>.sample(&mut rand)) <= 1.0);
+        assert!(norm(&UnitBall::<5>.sample(&mut rand)) <= 1.0);
+    }
+}
+
+#[test]
+fn unit_sphere_is_centred() {
+    let mut rand = Wyrand::default();
+    const N: u32 = 50_000;
+    let mut sums = [0.0; 3];
+    for _ in 0..N {
+        for (sum, x) in sums.iter_mut().zip(UnitSphere.sample(&mut rand)) {
+            *sum += x;
+        }
+    }
+    for sum in sums {
+        assert!((sum / f64::from(N)).abs() < 0.01, "sum={sum}");
+    }
+}
+
+#[test]
+fn unit_ball_radius_distribution() {
+    // the fraction of points within radius r of the centre of a 3-ball is r^3
+    let mut rand = Wyrand::default();
+    const N: u32 = 50_000;
+    let inner = (0..N).filter(|_| norm(&UnitBall::<3>.sample(&mut rand)) < 0.5).count();
+    #[allow(clippy::cast_precision_loss)]
+    let fraction = inner as f64 / f64::from(N);
+    assert!((fraction - 0.125).abs() < 0.005, "fraction={fraction}");
+}
+
+#[test]
+#[should_panic(expected = "zero dimensions")]
+fn fill_unit_hypersphere_empty() {
+    fill_unit_hypersphere(&mut Wyrand::default(), &mut []);
+}
+
+#[test]
+#[should_panic(expected = "zero dimensions")]
+fn fill_unit_ball_empty() {
+    fill_unit_ball(&mut Wyrand::default(), &mut []);
+}
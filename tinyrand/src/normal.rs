@@ -0,0 +1,21 @@
+//! Sampling from the [normal (Gaussian) distribution](https://en.wikipedia.org/wiki/Normal_distribution).
+
+use crate::Rand;
+
+/// Samples from the standard normal distribution, using Marsaglia's
+/// [polar method](https://en.wikipedia.org/wiki/Marsaglia_polar_method). The method generates a pair of independent
+/// variates; only one is used, so that distributions remain stateless.
+#[inline(always)]
+pub(crate) fn standard_normal(rand: &mut impl Rand) -> f64 {
+    loop {
+        let u = 2.0 * rand.next_f64() - 1.0;
+        let v = 2.0 * rand.next_f64() - 1.0;
+        let s = u * u + v * v;
+        if s < 1.0 && s > 0.0 {
+            return u * libm::sqrt(-2.0 * libm::log(s) / s);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
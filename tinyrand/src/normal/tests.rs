@@ -0,0 +1,37 @@
+use crate::normal::standard_normal;
+use crate::test_mock::TestMock;
+use crate::Wyrand;
+
+#[test]
+fn standard_normal_moments() {
+    let mut rand = Wyrand::default();
+    const N: u32 = 100_000;
+    let (mut sum, mut sum_sq) = (0.0, 0.0);
+    for _ in 0..N {
+        let x = standard_normal(&mut rand);
+        sum += x;
+        sum_sq += x * x;
+    }
+    let mean = sum / f64::from(N);
+    let variance = sum_sq / f64::from(N) - mean * mean;
+    assert!(mean.abs() < 0.025, "mean={mean}");
+    assert!((variance - 1.0).abs() < 0.025, "variance={variance}");
+}
+
+#[test]
+fn polar_method_rejects_outside_unit_disc() {
+    // the first pair maps to the corner (-1, -1) of the square, which lies outside the unit disc
+    // and is rejected; the second pair maps to (0.5, 0.5), which is accepted
+    let mut invocations = 0;
+    let mut rand = TestMock::new(|_| {
+        invocations += 1;
+        if invocations <= 2 {
+            0
+        } else {
+            3 << 62
+        }
+    });
+    let x = standard_normal(&mut rand);
+    assert!(x > 0.0);
+    assert!(x.is_finite());
+}
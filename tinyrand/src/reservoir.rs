@@ -0,0 +1,113 @@
+//! [Reservoir sampling](https://en.wikipedia.org/wiki/Reservoir_sampling) from iterators of
+//! unknown length.
+//!
+//! Sampling uses Algorithm L of K.-H. Li, which computes how many items to skip between
+//! replacements, rather than drawing a random number for every item. For a stream of _n_ items and
+//! a reservoir of _k_, the expected number of random draws is O(_k_(1 + log(_n_/_k_))).
+
+use crate::Rand;
+
+/// Random selection of items from iterators.
+///
+/// This trait is implemented for all [`Rand`] types, so its methods may be invoked on any RNG.
+pub trait RandIter {
+    /// Chooses an item from the iterator uniformly at random, returning `None` if the iterator
+    /// is empty. The iterator is consumed without buffering its items.
+    ///
+    /// # Examples
+    /// ```
+    /// use tinyrand::{RandIter, StdRand};
+    /// let mut rand = StdRand::default();
+    /// let line = rand.choose_from_iter("alpha\nbravo\ncharlie".lines());
+    /// assert!(line.is_some());
+    /// ```
+    fn choose_from_iter<I: IntoIterator>(&mut self, iter: I) -> Option<I::Item>;
+
+    /// Chooses items from the iterator uniformly at random, without replacement, filling the given
+    /// reservoir. Each item is included with a probability of _k_/_n_, where _k_ is the size of the
+    /// reservoir and _n_ is the number of items in the iterator. Returns the number of items written
+    /// to the reservoir, which is less than _k_ if the iterator yields fewer than _k_ items.
+    ///
+    /// The items in the reservoir are not in random order.
+    ///
+    /// # Examples
+    /// ```
+    /// use tinyrand::{RandIter, StdRand};
+    /// let mut rand = StdRand::default();
+    /// let mut reservoir = [0; 10];
+    /// let filled = rand.fill_from_iter(0..1_000_000, &mut reservoir);
+    /// assert_eq!(10, filled);
+    /// ```
+    fn fill_from_iter<I: IntoIterator>(&mut self, iter: I, reservoir: &mut [I::Item]) -> usize;
+}
+
+impl<R: Rand> RandIter for R {
+    #[inline(always)]
+    fn choose_from_iter<I: IntoIterator>(&mut self, iter: I) -> Option<I::Item> {
+        let mut chosen = None;
+        algorithm_l(self, iter, 1, |_, item| chosen = Some(item));
+        chosen
+    }
+
+    #[inline(always)]
+    fn fill_from_iter<I: IntoIterator>(&mut self, iter: I, reservoir: &mut [I::Item]) -> usize {
+        algorithm_l(self, iter, reservoir.len(), |index, item| reservoir[index] = item)
+    }
+}
+
+/// Runs Algorithm L over the iterator with a reservoir of size `k`, passing each selected item to
+/// `store`, along with its index in `0..k`. The first `k` items are stored at consecutive indices;
+/// each subsequent selection replaces a random index. Returns the number of items in the reservoir
+/// (the lesser of `k` and the length of the iterator).
+///
+/// This function does not own the reservoir, making it suitable for building custom reservoirs
+/// (e.g., ones that grow as they are filled).
+pub fn algorithm_l<T>(
+    rand: &mut impl Rand,
+    iter: impl IntoIterator<Item = T>,
+    k: usize,
+    mut store: impl FnMut(usize, T),
+) -> usize {
+    let mut iter = iter.into_iter();
+    for index in 0..k {
+        match iter.next() {
+            None => return index,
+            Some(item) => store(index, item),
+        }
+    }
+    if k == 0 {
+        return 0;
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let k_f = k as f64;
+    let mut w = libm::exp(libm::log(next_open_f64(rand)) / k_f);
+    loop {
+        // the float-to-int conversion saturates, so an infinite skip exhausts the iterator
+        #[allow(clippy::cast_possible_truncation)]
+        #[allow(clippy::cast_sign_loss)]
+        let skip = libm::floor(libm::log(next_open_f64(rand)) / libm::log1p(-w)) as usize;
+        match iter.nth(skip) {
+            None => return k,
+            Some(item) => {
+                let index = if k == 1 { 0 } else { rand.next_lim_usize(k) };
+                store(index, item);
+                w *= libm::exp(libm::log(next_open_f64(rand)) / k_f);
+            }
+        }
+    }
+}
+
+/// Generates a random `f64` in the open interval (0, 1), whose logarithm is always finite.
+#[inline(always)]
+fn next_open_f64(rand: &mut impl Rand) -> f64 {
+    loop {
+        let u = rand.next_f64();
+        if u > 0.0 {
+            return u;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,69 @@
+use crate::reservoir::{algorithm_l, RandIter};
+use crate::test_mock::{fixed, TestMock};
+use crate::Wyrand;
+
+#[test]
+fn choose_from_empty() {
+    let mut rand = TestMock::new(|_| unreachable!());
+    assert_eq!(None, rand.choose_from_iter(0..0));
+}
+
+#[test]
+fn choose_from_singleton() {
+    let mut rand = Wyrand::default();
+    assert_eq!(Some(42), rand.choose_from_iter([42]));
+}
+
+#[test]
+fn choose_covers_iter() {
+    let mut rand = Wyrand::default();
+    let mut seen = [false; 5];
+    for _ in 0..100 {
+        seen[rand.choose_from_iter(0..5).unwrap()] = true;
+    }
+    assert_eq!([true; 5], seen);
+}
+
+#[test]
+fn fill_shorter_iter() {
+    let mut rand = TestMock::new(|_| unreachable!());
+    let mut reservoir = [0; 5];
+    assert_eq!(3, rand.fill_from_iter(1..4, &mut reservoir));
+    assert_eq!([1, 2, 3, 0, 0], reservoir);
+}
+
+#[test]
+fn fill_empty_reservoir() {
+    let mut rand = TestMock::new(|_| unreachable!());
+    assert_eq!(0, rand.fill_from_iter(0..10, &mut []));
+}
+
+#[test]
+fn fill_yields_distinct_items() {
+    let mut rand = Wyrand::default();
+    let mut reservoir = [0; 10];
+    for _ in 0..100 {
+        assert_eq!(10, rand.fill_from_iter(0..100, &mut reservoir));
+        let mut sorted = reservoir;
+        sorted.sort_unstable();
+        assert!(sorted.windows(2).all(|pair| pair[0] < pair[1]), "{reservoir:?}");
+    }
+}
+
+#[test]
+fn fill_from_huge_iter() {
+    // small uniform draws lead to large skips, so most of the stream is never examined
+    let mut rand = Wyrand::default();
+    let mut reservoir = [0; 3];
+    assert_eq!(3, rand.fill_from_iter(0..u64::MAX, &mut reservoir));
+}
+
+#[test]
+fn largest_draws_skip_nothing() {
+    // a uniform draw approaching 1 has a logarithm approaching 0, so that no item is skipped and
+    // every item is stored
+    let mut rand = TestMock::new(fixed(u64::MAX));
+    let mut stored = 0;
+    assert_eq!(2, algorithm_l(&mut rand, 0..10, 2, |_, _| stored += 1));
+    assert_eq!(10, stored);
+}
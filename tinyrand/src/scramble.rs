@@ -0,0 +1,78 @@
+//! Seed scrambling, so that nearby seeds yield unrelated streams.
+//!
+//! Several generators use their seed directly as state. For [`Wyrand`](crate::Wyrand), seeds 1, 2
+//! and 3 start from adjacent states; for [`Xorshift`](crate::Xorshift), the streams seeded with
+//! _s_ and _s_ + 1 are related linearly. Seeding by loop index is thus liable to produce correlated
+//! runs. Scrambling the seed first breaks up such relationships.
+
+use crate::Seeded;
+use core::marker::PhantomData;
+
+/// Scrambles a seed, mapping nearby seeds to unrelated ones.
+///
+/// The scrambler is a bijection — distinct seeds remain distinct — being the addition of the
+/// golden-ratio constant followed by D. Stafford's `Mix13` finaliser (as used in `SplitMix64`).
+///
+/// # Stability
+/// This is version 1 of the scrambler. It is frozen: a given seed always scrambles to the same
+/// value, on every platform and in future versions of this crate. (Should a different scrambler
+/// be adopted, it will be added alongside this one, rather than replacing it.)
+///
+/// # Examples
+/// ```
+/// use tinyrand::scramble::scramble;
+/// assert_eq!(0xE220_A839_7B1D_CDAF, scramble(0));
+/// ```
+#[inline(always)]
+pub fn scramble(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// A [`Seeded`] adapter that [scrambles](scramble) every seed before passing it on to `S`.
+///
+/// Useful where a type (rather than a method) selects the seeding strategy — for instance, in code
+/// that is generic over [`Seeded`]. `Scrambled::<S>::seed(seed)` is equivalent to
+/// [`S::seed_scrambled(seed)`](Seeded::seed_scrambled).
+///
+/// # Examples
+/// ```
+/// use tinyrand::{Rand, Seeded, Wyrand};
+/// use tinyrand::scramble::Scrambled;
+/// for run in 0..4 {
+///     let mut rand = Scrambled::<Wyrand>::seed(run);
+///     println!("run {run}: {}", rand.next_u64());
+/// }
+/// ```
+pub struct Scrambled<S>(PhantomData<S>);
+
+impl<S: Seeded> Seeded for Scrambled<S> {
+    type R = S::R;
+
+    #[inline(always)]
+    fn seed(seed: u64) -> Self::R {
+        S::seed_scrambled(seed)
+    }
+
+    /// Both halves of the seed are scrambled before it is passed on to `S`, so that generators
+    /// that use all 128 bits (such as [`Xoshiro`](crate::Xoshiro)) see a scrambled seed. A zero
+    /// upper half is left as is, so that seeds below 2<sup>64</sup> remain equivalent to those
+    /// passed to [`Seeded::seed`].
+    #[inline(always)]
+    fn seed_u128(seed: u128) -> Self::R {
+        let (low, high) = (seed as u64, (seed >> 64) as u64);
+        let high = if high == 0 { 0 } else { scramble(high) };
+        S::seed_u128(u128::from(high) << 64 | u128::from(scramble(low)))
+    }
+
+    /// The generator draws its seed directly from `rand`, whose output needs no scrambling.
+    #[inline(always)]
+    fn from_rand<G: crate::Rand + ?Sized>(rand: &mut G) -> Self::R {
+        S::from_rand(rand)
+    }
+}
+
+#[cfg(test)]
+mod tests;
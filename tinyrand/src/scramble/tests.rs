@@ -0,0 +1,47 @@
+use super::*;
+use crate::{Counter, Rand, SplitMix, Wyrand, Xoshiro};
+use alloc::collections::BTreeSet;
+
+#[test]
+fn vectors() {
+    // the first outputs of SplitMix64 seeded with the same values
+    assert_eq!(0xE220_A839_7B1D_CDAF, scramble(0));
+    assert_eq!(0x910A_2DEC_8902_5CC1, scramble(1));
+    assert_eq!(0xE4D9_7177_1B65_2C20, scramble(u64::MAX));
+}
+
+#[test]
+fn nearby_seeds_distinct() {
+    let scrambled: BTreeSet<_> = (0..10_000).map(scramble).collect();
+    assert_eq!(10_000, scrambled.len());
+    // adjacent seeds differ in about half of their bits
+    for seed in 0..1_000 {
+        let flipped = (scramble(seed) ^ scramble(seed + 1)).count_ones();
+        assert!((8..=56).contains(&flipped), "seed: {seed}, flipped: {flipped}");
+    }
+}
+
+#[test]
+fn scrambled_seeded() {
+    assert_eq!(scramble(42), Scrambled::<Counter>::seed(42).next_u64());
+    assert_eq!(Wyrand::seed_scrambled(42).next_u64(), Scrambled::<Wyrand>::seed(42).next_u64());
+
+    // seeds below 2^64 are equivalent to those passed to seed
+    for seed in [0, 1, 42, u64::MAX] {
+        assert_eq!(
+            Scrambled::<Wyrand>::seed(seed).next_u64(),
+            Scrambled::<Wyrand>::seed_u128(u128::from(seed)).next_u64()
+        );
+    }
+
+    // otherwise, both halves of a 128-bit seed are scrambled
+    let seed = 3 << 64 | 42;
+    let scrambled = u128::from(scramble(3)) << 64 | u128::from(scramble(42));
+    assert_eq!(Wyrand::seed_u128(scrambled).next_u64(), Scrambled::<Wyrand>::seed_u128(seed).next_u64());
+    assert_eq!(Xoshiro::seed_u128(scrambled), Scrambled::<Xoshiro>::seed_u128(seed));
+    assert_ne!(Xoshiro::seed_u128(seed), Scrambled::<Xoshiro>::seed_u128(seed));
+
+    // seeding from another generator bypasses the scrambler
+    let from_rand = Scrambled::<Xoshiro>::from_rand(&mut SplitMix::seed(7));
+    assert_eq!(Xoshiro::from_rand(&mut SplitMix::seed(7)), from_rand);
+}
@@ -0,0 +1,273 @@
+//! Hierarchical seed sequences, for deriving many independent seeds from user-supplied entropy.
+
+use crate::{SeedSource, SeedableFromBytes, Seeded};
+use core::convert::Infallible;
+
+/// The number of 32-bit words in the entropy pool.
+const POOL_SIZE: usize = 4;
+
+const INIT_A: u32 = 0x43B0_D7E5;
+const MULT_A: u32 = 0x931E_8875;
+const INIT_B: u32 = 0x8B51_F9DD;
+const MULT_B: u32 = 0x58F3_8DED;
+const MIX_MULT_L: u32 = 0xCA01_F9DD;
+const MIX_MULT_R: u32 = 0x4973_F715;
+const XSHIFT: u32 = u32::BITS / 2;
+
+/// Precedes each kind of input, so that distinct inputs cannot produce the same stream of words.
+const TAG_U64: u32 = 1;
+const TAG_BYTES: u32 = 2;
+const TAG_CHILD: u32 = 3;
+
+/// Hashes arbitrary entropy — numbers, strings and bytes — into well-mixed seeds, and spawns
+/// child sequences that are independent of their parent and of one another.
+///
+/// A sequence is identified by its inputs, in order: the entropy it was created with and the
+/// indices of the children leading to it. `SeedSequence::new(42).with_str("exp").child(3).child(7)`
+/// always yields the same seeds, no matter how (or whether) other sequences were derived.
+///
+/// The mixing algorithm is that of `numpy.random.SeedSequence`, being M. O'Neill's
+/// [`seed_seq_fe`](https://www.pcg-random.org/posts/developing-a-seed_seq-alternative.html) with
+/// a 128-bit pool. The inputs are encoded differently, so the seeds are not interchangeable with
+/// those of `numpy`.
+///
+/// # Stability
+/// The seeds produced for a given sequence of inputs are part of the public API, and will not
+/// change in a minor or patch release.
+///
+/// # Examples
+/// ```
+/// use tinyrand::{Rand, SeedSequence, StdRand};
+/// let experiment = SeedSequence::new(42).with_str("fluid-sim");
+/// let replica = experiment.child(3).child(7).child(2); // run 3, worker 7, replica 2
+/// let mut rand = replica.rand::<StdRand>();
+/// println!("{}", rand.next_u64());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeedSequence {
+    /// The raw input words, until [`POOL_SIZE`] have been absorbed; thereafter, the mixed pool.
+    pool: [u32; POOL_SIZE],
+    absorbed: u64,
+    hash_const: u32,
+    spawned: u64,
+}
+
+impl SeedSequence {
+    /// Creates a new sequence from the given entropy. Further entropy may be mixed in with
+    /// [`with_u64`](Self::with_u64), [`with_str`](Self::with_str) and
+    /// [`with_bytes`](Self::with_bytes).
+    pub fn new(entropy: u64) -> Self {
+        Self {
+            pool: [0; POOL_SIZE],
+            absorbed: 0,
+            hash_const: INIT_A,
+            spawned: 0,
+        }
+        .with_u64(entropy)
+    }
+
+    /// Mixes in a `u64`, such as a run index.
+    #[must_use]
+    pub fn with_u64(mut self, entropy: u64) -> Self {
+        self.absorb(TAG_U64);
+        self.absorb_u64(entropy);
+        self
+    }
+
+    /// Mixes in a string, such as the name of an experiment. Equivalent to
+    /// [`with_bytes`](Self::with_bytes) over its UTF-8 encoding.
+    #[must_use]
+    pub fn with_str(self, entropy: &str) -> Self {
+        self.with_bytes(entropy.as_bytes())
+    }
+
+    /// Mixes in a byte string.
+    #[must_use]
+    pub fn with_bytes(mut self, entropy: &[u8]) -> Self {
+        self.absorb(TAG_BYTES);
+        self.absorb_u64(entropy.len() as u64);
+        let mut chunks = entropy.chunks_exact(4);
+        for chunk in &mut chunks {
+            self.absorb(u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]));
+        }
+        let remainder = chunks.remainder();
+        if !remainder.is_empty() {
+            let mut last = [0; 4];
+            last[..remainder.len()].copy_from_slice(remainder);
+            self.absorb(u32::from_le_bytes(last));
+        }
+        self
+    }
+
+    /// The child sequence with the given index. Children are independent of their parent and
+    /// of their siblings; a given index always yields the same child.
+    #[must_use]
+    pub fn child(&self, index: u64) -> Self {
+        let mut child = Self {
+            spawned: 0,
+            ..self.clone()
+        };
+        child.absorb(TAG_CHILD);
+        child.absorb_u64(index);
+        child
+    }
+
+    /// Spawns the next child sequence, being the [`child`](Self::child) whose index is the number
+    /// of children previously spawned.
+    #[must_use]
+    pub fn spawn(&mut self) -> Self {
+        let child = self.child(self.spawned);
+        self.spawned += 1;
+        child
+    }
+
+    /// The number of children spawned by [`spawn`](Self::spawn).
+    pub fn spawned(&self) -> u64 {
+        self.spawned
+    }
+
+    /// Fills the given slice with seed material. The output is a stream; filling a shorter slice
+    /// yields a prefix of the output for a longer one.
+    pub fn fill_u32(&self, out: &mut [u32]) {
+        let mut words = self.words();
+        for value in out {
+            *value = words.next_word();
+        }
+    }
+
+    /// Fills the given slice with seed material, each `u64` formed from a pair of consecutive
+    /// words of [`fill_u32`](Self::fill_u32), the first being the lower half.
+    pub fn fill_u64(&self, out: &mut [u64]) {
+        let mut words = self.words();
+        for value in out {
+            let low = words.next_word();
+            *value = u64::from(low) | u64::from(words.next_word()) << 32;
+        }
+    }
+
+    /// Fills the given slice with seed material, being the little-endian bytes of the words of
+    /// [`fill_u32`](Self::fill_u32).
+    pub fn fill_bytes(&self, out: &mut [u8]) {
+        let mut words = self.words();
+        for chunk in out.chunks_mut(4) {
+            chunk.copy_from_slice(&words.next_word().to_le_bytes()[..chunk.len()]);
+        }
+    }
+
+    /// A 64-bit seed, being the first word of [`fill_u64`](Self::fill_u64).
+    pub fn seed(&self) -> u64 {
+        let mut seed = [0];
+        self.fill_u64(&mut seed);
+        seed[0]
+    }
+
+    /// A 128-bit seed, whose lower and upper halves are the first two words of
+    /// [`fill_u64`](Self::fill_u64).
+    pub fn seed_u128(&self) -> u128 {
+        let mut seed = [0; 2];
+        self.fill_u64(&mut seed);
+        u128::from(seed[0]) | u128::from(seed[1]) << 64
+    }
+
+    /// Creates a [`Seeded`] generator from [`seed_u128`](Self::seed_u128).
+    pub fn rand<S: Seeded>(&self) -> S::R {
+        S::seed_u128(self.seed_u128())
+    }
+
+    /// Creates a [`SeedableFromBytes`] generator, filling its seed with
+    /// [`fill_bytes`](Self::fill_bytes).
+    pub fn rand_from_bytes<S: SeedableFromBytes>(&self) -> S {
+        let mut seed = S::Seed::default();
+        self.fill_bytes(seed.as_mut());
+        S::from_seed_bytes(seed)
+    }
+
+    fn words(&self) -> Words {
+        Words {
+            pool: self.mixed_pool(),
+            index: 0,
+            hash_const: INIT_B,
+        }
+    }
+
+    /// The pool, with any shortfall in the input padded with zeros.
+    fn mixed_pool(&self) -> [u32; POOL_SIZE] {
+        let mut padded = self.clone();
+        while padded.absorbed < POOL_SIZE as u64 {
+            padded.absorb(0);
+        }
+        padded.pool
+    }
+
+    fn absorb_u64(&mut self, value: u64) {
+        self.absorb(value as u32);
+        self.absorb((value >> 32) as u32);
+    }
+
+    fn absorb(&mut self, word: u32) {
+        if self.absorbed < POOL_SIZE as u64 {
+            self.pool[self.absorbed as usize] = word;
+            if self.absorbed + 1 == POOL_SIZE as u64 {
+                // the pool is full: hash its words, then mix every word into every other
+                for i in 0..POOL_SIZE {
+                    self.pool[i] = self.hashmix(self.pool[i]);
+                }
+                for src in 0..POOL_SIZE {
+                    for dst in 0..POOL_SIZE {
+                        if src != dst {
+                            let hashed = self.hashmix(self.pool[src]);
+                            self.pool[dst] = mix(self.pool[dst], hashed);
+                        }
+                    }
+                }
+            }
+        } else {
+            for dst in 0..POOL_SIZE {
+                let hashed = self.hashmix(word);
+                self.pool[dst] = mix(self.pool[dst], hashed);
+            }
+        }
+        self.absorbed += 1;
+    }
+
+    fn hashmix(&mut self, mut value: u32) -> u32 {
+        value ^= self.hash_const;
+        self.hash_const = self.hash_const.wrapping_mul(MULT_A);
+        value = value.wrapping_mul(self.hash_const);
+        value ^ (value >> XSHIFT)
+    }
+}
+
+/// The output stream of a [`SeedSequence`], cycling over its pool.
+struct Words {
+    pool: [u32; POOL_SIZE],
+    index: usize,
+    hash_const: u32,
+}
+
+impl Words {
+    fn next_word(&mut self) -> u32 {
+        let mut word = self.pool[self.index] ^ self.hash_const;
+        self.index = (self.index + 1) % POOL_SIZE;
+        self.hash_const = self.hash_const.wrapping_mul(MULT_B);
+        word = word.wrapping_mul(self.hash_const);
+        word ^ (word >> XSHIFT)
+    }
+}
+
+fn mix(x: u32, y: u32) -> u32 {
+    let result = MIX_MULT_L.wrapping_mul(x).wrapping_sub(MIX_MULT_R.wrapping_mul(y));
+    result ^ (result >> XSHIFT)
+}
+
+/// Each seed is that of the next [spawned](SeedSequence::spawn) child.
+impl SeedSource for SeedSequence {
+    type Error = Infallible;
+
+    fn next_seed(&mut self) -> Result<u64, Self::Error> {
+        Ok(self.spawn().seed())
+    }
+}
+
+#[cfg(test)]
+mod tests;
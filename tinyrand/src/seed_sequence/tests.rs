@@ -0,0 +1,129 @@
+use super::*;
+use crate::{Rand, StdRand, Xoshiro};
+use alloc::collections::BTreeSet;
+
+// Test vectors, computed independently from a direct (non-streaming) implementation of the
+// algorithm. These must never change; see the stability guarantee.
+
+#[test]
+fn vectors_u64_entropy() {
+    let cases: [(u64, [u32; 8]); 3] = [
+        (0, [1_835_504_127, 1_731_038_949, 1_320_224_556, 2_330_041_505, 321_059_914, 1_226_144_109, 2_879_408_573, 3_503_041_500]),
+        (42, [3_394_225_019, 2_630_397_851, 1_285_277_108, 1_314_967_157, 1_073_381_438, 554_204_763, 859_651_538, 3_917_184_078]),
+        (u64::MAX, [3_328_717_479, 4_073_189_670, 2_751_291_035, 2_044_536_592, 1_281_509_133, 2_741_613_576, 2_973_546_666, 1_798_904_916]),
+    ];
+    for (entropy, expected) in cases {
+        let mut words = [0; 8];
+        SeedSequence::new(entropy).fill_u32(&mut words);
+        assert_eq!(expected, words, "entropy: {entropy}");
+    }
+}
+
+#[test]
+fn vectors_u64_output() {
+    let mut words = [0; 4];
+    SeedSequence::new(42).fill_u64(&mut words);
+    assert_eq!([11_297_472_748_907_905_915, 5_647_740_935_914_374_580, 2_380_291_333_445_812_286, 16_824_177_508_281_564_626], words);
+    assert_eq!(11_297_472_748_907_905_915, SeedSequence::new(42).seed());
+}
+
+#[test]
+fn vectors_str_entropy() {
+    let mut words = [0; 4];
+    SeedSequence::new(42).with_str("fluid-sim").fill_u64(&mut words);
+    assert_eq!([17_638_353_000_828_572_890, 12_066_074_362_983_153_104, 649_495_404_882_178_799, 8_691_925_945_684_693_735], words);
+}
+
+#[test]
+fn vectors_children() {
+    let mut words = [0; 4];
+    SeedSequence::new(42).with_str("fluid-sim").child(3).child(7).child(2).fill_u64(&mut words);
+    assert_eq!([5_308_847_977_741_045_189, 2_637_505_991_909_694_008, 1_214_212_191_475_878_645, 12_118_398_307_699_980_419], words);
+}
+
+#[test]
+fn vectors_more_entropy() {
+    assert_eq!(17_450_624_244_909_667_976, SeedSequence::new(42).with_bytes(&[]).seed());
+    assert_eq!(423_798_859_271_250_719, SeedSequence::new(42).with_u64(1).seed());
+}
+
+#[test]
+fn output_is_a_stream() {
+    let seq = SeedSequence::new(7).with_str("stream");
+    let mut long = [0; 9];
+    seq.fill_u32(&mut long);
+    let mut short = [0; 3];
+    seq.fill_u32(&mut short);
+    assert_eq!(long[..3], short);
+
+    let mut bytes = [0; 9 * 4 - 1];
+    seq.fill_bytes(&mut bytes);
+    for (word, chunk) in long.iter().zip(bytes.chunks(4)) {
+        assert_eq!(&word.to_le_bytes()[..chunk.len()], chunk);
+    }
+
+    assert_eq!(u128::from(seq.seed()), seq.seed_u128() & u128::from(u64::MAX));
+}
+
+#[test]
+fn inputs_are_unambiguous() {
+    let seeds = [
+        SeedSequence::new(0),
+        SeedSequence::new(1),
+        SeedSequence::new(0).with_u64(0),
+        SeedSequence::new(0).with_bytes(&[]),
+        SeedSequence::new(0).with_bytes(&[0]),
+        SeedSequence::new(0).with_bytes(&[0, 0, 0, 0]),
+        SeedSequence::new(0).with_str("ab").with_str("c"),
+        SeedSequence::new(0).with_str("a").with_str("bc"),
+        SeedSequence::new(0).child(0),
+        SeedSequence::new(0).child(0).child(0),
+        SeedSequence::new(0).child(1),
+    ]
+    .map(|seq| seq.seed_u128());
+    assert_eq!(seeds.len(), seeds.iter().collect::<BTreeSet<_>>().len());
+}
+
+#[test]
+fn spawn() {
+    let mut parent = SeedSequence::new(42);
+    assert_eq!(0, parent.spawned());
+    let first = parent.spawn();
+    let second = parent.spawn();
+    assert_eq!(2, parent.spawned());
+    assert_eq!(parent.child(0), first);
+    assert_eq!(parent.child(1), second);
+    assert_eq!(0, first.spawned());
+
+    // spawning doesn't affect the parent's output
+    assert_eq!(SeedSequence::new(42).seed(), parent.seed());
+}
+
+#[test]
+fn children_distinct() {
+    let parent = SeedSequence::new(42);
+    let seeds: BTreeSet<_> = (0..1_000).map(|index| parent.child(index).seed()).collect();
+    assert_eq!(1_000, seeds.len());
+    assert!(!seeds.contains(&parent.seed()));
+}
+
+#[test]
+fn seed_source() {
+    let mut source = SeedSequence::new(42);
+    let rand = StdRand::from_source(&mut source);
+    assert!(rand.is_ok());
+    assert_eq!(Ok(SeedSequence::new(42).child(1).seed()), source.next_seed());
+}
+
+#[test]
+fn seeded_generators() {
+    let seq = SeedSequence::new(42);
+    assert_eq!(Xoshiro::seed_u128(seq.seed_u128()), seq.rand::<Xoshiro>());
+
+    let mut bytes = [0; 32];
+    seq.fill_bytes(&mut bytes);
+    assert_eq!(Xoshiro::from_seed_bytes(bytes), seq.rand_from_bytes::<Xoshiro>());
+
+    let mut rand = seq.rand::<StdRand>();
+    assert_eq!(StdRand::seed_u128(seq.seed_u128()).next_u64(), rand.next_u64());
+}
@@ -0,0 +1,44 @@
+//! Sources of seeds, as distinct from sources of random numbers.
+
+use core::convert::Infallible;
+
+/// A source of seeds for [`Seeded`](crate::Seeded) RNGs.
+///
+/// Seeds need not be uniformly distributed or independent of one another — successive clock
+/// readings, for example, are highly correlated — so a [`SeedSource`] is not a [`Rand`](crate::Rand),
+/// and cannot be used in its place. Sources that may fail (e.g., because a resource is unavailable)
+/// report the failure through [`SeedSource::Error`]; those that cannot fail use [`Infallible`].
+pub trait SeedSource {
+    /// The reason a seed could not be obtained.
+    type Error;
+
+    /// Obtains the next seed.
+    ///
+    /// # Errors
+    /// If the source is unable to produce a seed.
+    fn next_seed(&mut self) -> Result<u64, Self::Error>;
+}
+
+/// A [`SeedSource`] that always produces the same seed. Useful for reproducing a run in code
+/// that otherwise takes its seed from elsewhere.
+///
+/// # Examples
+/// ```
+/// use tinyrand::{FixedSeed, Rand, Seeded, StdRand};
+/// let mut rand = StdRand::from_source(&mut FixedSeed(42)).unwrap();
+/// assert_eq!(StdRand::seed(42).next_u64(), rand.next_u64());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedSeed(pub u64);
+
+impl SeedSource for FixedSeed {
+    type Error = Infallible;
+
+    #[inline(always)]
+    fn next_seed(&mut self) -> Result<u64, Self::Error> {
+        Ok(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests;
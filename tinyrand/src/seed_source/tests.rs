@@ -0,0 +1,37 @@
+use super::*;
+use crate::{Rand, Seeded, StdRand};
+
+#[test]
+fn fixed_seed() {
+    let mut source = FixedSeed(42);
+    assert_eq!(Ok(42), source.next_seed());
+    assert_eq!(Ok(42), source.next_seed());
+}
+
+#[test]
+fn from_source() {
+    let mut rand = StdRand::from_source(&mut FixedSeed(42)).unwrap();
+    assert_eq!(StdRand::seed(42).next_u64(), rand.next_u64());
+}
+
+struct Failing;
+
+impl SeedSource for Failing {
+    type Error = &'static str;
+
+    fn next_seed(&mut self) -> Result<u64, Self::Error> {
+        Err("unavailable")
+    }
+}
+
+#[test]
+fn from_failing_source() {
+    assert_eq!(Some("unavailable"), StdRand::from_source(&mut Failing).err());
+}
+
+#[test]
+fn from_dyn_source() {
+    let source: &mut dyn SeedSource<Error = Infallible> = &mut FixedSeed(7);
+    let mut rand = StdRand::from_source(source).unwrap();
+    assert_eq!(StdRand::seed(7).next_u64(), rand.next_u64());
+}
@@ -0,0 +1,98 @@
+//! Extensions for randomly permuting slices and selecting their elements.
+
+use crate::Rand;
+
+/// Random operations over slices.
+///
+/// This trait is implemented for all [`Rand`] types, so its methods may be invoked on any RNG.
+pub trait RandSlice {
+    /// Shuffles the slice in place, using the [Fisher–Yates](https://en.wikipedia.org/wiki/Fisher%E2%80%93Yates_shuffle)
+    /// algorithm. Every permutation of the slice is equally likely.
+    ///
+    /// # Examples
+    /// ```
+    /// use tinyrand::{RandSlice, StdRand};
+    /// let mut rand = StdRand::default();
+    /// let mut cards = [1, 2, 3, 4, 5];
+    /// rand.shuffle(&mut cards);
+    /// println!("{cards:?}");
+    /// ```
+    fn shuffle<T>(&mut self, slice: &mut [T]);
+
+    /// Partially shuffles the slice in place, such that its first `k` elements are chosen uniformly
+    /// at random, without replacement, and are themselves in random order. The remaining elements
+    /// are left in an unspecified order. Returns the shuffled prefix, comprising `k` elements.
+    ///
+    /// If `k` exceeds the length of the slice, the entire slice is shuffled and returned. The cost is
+    /// proportional to `k`, rather than to the length of the slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use tinyrand::{RandSlice, StdRand};
+    /// let mut rand = StdRand::default();
+    /// let mut replicas = ["a", "b", "c", "d", "e"];
+    /// let chosen = rand.partial_shuffle(&mut replicas, 2);
+    /// assert_eq!(2, chosen.len());
+    /// ```
+    fn partial_shuffle<'a, T>(&mut self, slice: &'a mut [T], k: usize) -> &'a mut [T];
+
+    /// Chooses an element of the slice uniformly at random, returning `None` if the slice is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use tinyrand::{RandSlice, StdRand};
+    /// let mut rand = StdRand::default();
+    /// let replicas = ["a", "b", "c"];
+    /// let replica = rand.choose(&replicas).unwrap();
+    /// assert!(replicas.contains(replica));
+    /// ```
+    fn choose<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T>;
+
+    /// Chooses an element of the slice uniformly at random, returning a mutable reference to it, or
+    /// `None` if the slice is empty.
+    fn choose_mut<'a, T>(&mut self, slice: &'a mut [T]) -> Option<&'a mut T>;
+}
+
+impl<R: Rand> RandSlice for R {
+    #[inline(always)]
+    fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.next_lim_usize(i + 1);
+            slice.swap(i, j);
+        }
+    }
+
+    #[inline(always)]
+    fn partial_shuffle<'a, T>(&mut self, slice: &'a mut [T], k: usize) -> &'a mut [T] {
+        let len = slice.len();
+        let k = k.min(len);
+        // the last element of a full shuffle has nowhere else to go, so its draw is skipped
+        for i in 0..k.min(len.saturating_sub(1)) {
+            let j = i + self.next_lim_usize(len - i);
+            slice.swap(i, j);
+        }
+        &mut slice[..k]
+    }
+
+    #[inline(always)]
+    fn choose<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T> {
+        if slice.is_empty() {
+            None
+        } else {
+            Some(&slice[self.next_lim_usize(slice.len())])
+        }
+    }
+
+    #[inline(always)]
+    fn choose_mut<'a, T>(&mut self, slice: &'a mut [T]) -> Option<&'a mut T> {
+        if slice.is_empty() {
+            None
+        } else {
+            let index = self.next_lim_usize(slice.len());
+            Some(&mut slice[index])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,113 @@
+use crate::slice::RandSlice;
+use crate::test_mock::{fixed, TestMock};
+use crate::Wyrand;
+
+#[test]
+fn shuffle_with_lowest_draws() {
+    // every draw selects the first element, rotating the slice
+    let mut rand = TestMock::new(fixed(1));
+    let mut slice = [0, 1, 2, 3];
+    rand.shuffle(&mut slice);
+    assert_eq!([1, 2, 3, 0], slice);
+}
+
+#[test]
+fn shuffle_with_highest_draws() {
+    // every draw selects the current element, leaving the slice in its original order
+    let mut rand = TestMock::new(fixed(u64::MAX));
+    let mut slice = [0, 1, 2, 3];
+    rand.shuffle(&mut slice);
+    assert_eq!([0, 1, 2, 3], slice);
+}
+
+#[test]
+fn shuffle_trivial_slices() {
+    let mut rand = TestMock::new(|_| unreachable!());
+    rand.shuffle::<u8>(&mut []);
+    let mut slice = [42];
+    rand.shuffle(&mut slice);
+    assert_eq!([42], slice);
+}
+
+#[test]
+fn shuffle_is_permutation() {
+    let mut rand = Wyrand::default();
+    let mut slice = [0usize; 100];
+    for (i, element) in slice.iter_mut().enumerate() {
+        *element = i;
+    }
+    rand.shuffle(&mut slice);
+    let mut seen = [false; 100];
+    for &element in &slice {
+        assert!(!seen[element]);
+        seen[element] = true;
+    }
+}
+
+#[test]
+fn partial_shuffle_with_highest_draws() {
+    // every draw selects the last element, which is swapped into the prefix
+    let mut rand = TestMock::new(fixed(u64::MAX));
+    let mut slice = [0, 1, 2, 3, 4];
+    let chosen = rand.partial_shuffle(&mut slice, 2);
+    assert_eq!([4, 0], chosen);
+    assert_eq!([4, 0, 2, 3, 1], slice);
+}
+
+#[test]
+fn partial_shuffle_zero() {
+    let mut rand = TestMock::new(|_| unreachable!());
+    let mut slice = [0, 1, 2];
+    assert!(rand.partial_shuffle(&mut slice, 0).is_empty());
+    assert_eq!([0, 1, 2], slice);
+}
+
+#[test]
+fn partial_shuffle_beyond_length() {
+    let mut rand = TestMock::new(fixed(1));
+    let mut slice = [0, 1, 2];
+    let chosen = rand.partial_shuffle(&mut slice, 10);
+    assert_eq!([0, 1, 2], chosen);
+    assert!(rand.partial_shuffle::<u8>(&mut [], 10).is_empty());
+}
+
+#[test]
+fn partial_shuffle_skips_last_draw() {
+    let mut rand = TestMock::new(fixed(1));
+    let mut slice = [0, 1, 2];
+    rand.partial_shuffle(&mut slice, 3);
+    assert_eq!(2, rand.state().next_u64_invocations());
+}
+
+#[test]
+fn choose_extremes() {
+    let slice = [0, 1, 2, 3, 4];
+    assert_eq!(Some(&0), TestMock::new(fixed(1)).choose(&slice));
+    assert_eq!(Some(&4), TestMock::new(fixed(u64::MAX)).choose(&slice));
+}
+
+#[test]
+fn choose_empty() {
+    let mut rand = TestMock::new(|_| unreachable!());
+    assert_eq!(None, rand.choose::<u8>(&[]));
+    assert_eq!(None, rand.choose_mut::<u8>(&mut []));
+}
+
+#[test]
+fn choose_mut_extremes() {
+    let mut slice = [0, 1, 2, 3, 4];
+    *TestMock::new(fixed(u64::MAX)).choose_mut(&mut slice).unwrap() = 40;
+    *TestMock::new(fixed(1)).choose_mut(&mut slice).unwrap() = 10;
+    assert_eq!([10, 1, 2, 3, 40], slice);
+}
+
+#[test]
+fn choose_covers_slice() {
+    let mut rand = Wyrand::default();
+    let slice = [0, 1, 2, 3, 4];
+    let mut seen = [false; 5];
+    for _ in 0..100 {
+        seen[*rand.choose(&slice).unwrap()] = true;
+    }
+    assert_eq!([true; 5], seen);
+}
@@ -1,6 +1,6 @@
 //! [`SplitMix`](https://dl.acm.org/doi/10.1145/2660193.2660195) RNG.
 
-use crate::{Rand, Seeded};
+use crate::{Rand, SeedableFromBytes, Seeded};
 
 pub struct SplitMix(u64);
 
@@ -32,5 +32,15 @@ impl Seeded for SplitMix {
     }
 }
 
+impl SeedableFromBytes for SplitMix {
+    type Seed = [u8; 8];
+
+    /// Equivalent to [`Seeded::seed`], with the seed read as a little-endian `u64`.
+    #[inline(always)]
+    fn from_seed_bytes(seed: Self::Seed) -> Self {
+        Self::seed(u64::from_le_bytes(seed))
+    }
+}
+
 #[cfg(test)]
 mod tests;
\ No newline at end of file
@@ -68,4 +68,9 @@ fn random_duration_splitmix() {
 fn range_types_max_splitmix() { range_types_max(SplitMix::default()); }
 
 #[test]
-fn numbers_differ_splitmix() { numbers_differ(SplitMix::default()) }
\ No newline at end of file
+fn numbers_differ_splitmix() { numbers_differ(SplitMix::default()) }
+
+#[test]
+fn seeding_splitmix() {
+    crate::tests::seeding_u64::<SplitMix>();
+}
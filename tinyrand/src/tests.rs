@@ -1,5 +1,5 @@
-use crate::{cutoff_u128, Probability, Rand, RandLim, RandRange, RefCellExt};
-use alloc::{format, vec};
+use crate::{cutoff_u128, wide_mul_u128, Counter, Probability, Rand, RandLim, RandRange, RefCellExt, SeedableFromBytes, Seeded, SplitMix};
+use alloc::format;
 use core::cell::RefCell;
 use core::ops::Range;
 use crate::test_mock::{counter, echo, fixed, TestMock};
@@ -12,6 +12,32 @@ pub fn next_types(mut rand: impl Rand) {
     assert_ne!(0, rand.next_usize());
 }
 
+/// Verifies the richer seeding methods of a generator with 64 bits of state against [`Seeded::seed`].
+pub fn seeding_u64<S>()
+where
+    S: Seeded<R = S> + SeedableFromBytes<Seed = [u8; 8]> + Rand,
+{
+    let first = |mut rand: S| rand.next_u64();
+    let seed = 0x0123_4567_89AB_CDEF;
+    let expected = first(S::seed(seed));
+    assert_eq!(expected, first(S::from_seed_bytes(u64::to_le_bytes(seed))));
+    assert_eq!(expected, first(S::seed_u128(u128::from(seed))));
+    assert_eq!(expected, first(S::from_rand(&mut Counter::new(seed))));
+
+    // strings and byte strings are seeded by their hash
+    assert_eq!(first(S::seed(7_686_241_392_188_081_660)), first(S::seed_from_str("order_book_fuzz")));
+    assert_eq!(first(S::seed(crate::wyhash::wyhash(&[1, 2, 3], 0))), first(S::seed_from_bytes(&[1, 2, 3])));
+    assert_ne!(first(S::seed_from_str("order_book_fuzz")), first(S::seed_from_str("order_book_fuzz_2")));
+
+    // scrambled seeding is seeding with the scrambled seed
+    assert_eq!(first(S::seed(crate::scramble::scramble(seed))), first(S::seed_scrambled(seed)));
+
+    // the upper half of the seed is folded into the lower
+    let offset = SplitMix::seed(1).next_u64();
+    assert_eq!(first(S::seed(seed ^ offset)), first(S::seed_u128(1 << 64 | u128::from(seed))));
+    assert_ne!(expected, first(S::seed_u128(1 << 64 | u128::from(seed))));
+}
+
 pub fn numbers_differ(mut rand: impl Rand) {
     assert_ne!(rand.next_u16() as u32, rand.next_u32());
     assert_ne!(rand.next_u16() as u64, rand.next_u64());
@@ -32,6 +58,20 @@ fn next_types_mock() {
     assert_ne!(0, mock.next_usize());
 }
 
+#[test]
+fn next_float_mock() {
+    let mut mock = TestMock::new(fixed(0));
+    assert_eq!(0.0, mock.next_f32());
+    assert_eq!(0.0, mock.next_f64());
+
+    let mut mock = TestMock::new(fixed(u64::MAX));
+    assert_eq!(1.0 - f32::EPSILON / 2.0, mock.next_f32());
+    assert_eq!(1.0 - f64::EPSILON / 2.0, mock.next_f64());
+
+    let mut mock = TestMock::new(fixed(u64::MAX >> 1));
+    assert_eq!(0.5 - f64::EPSILON / 2.0, mock.next_f64());
+}
+
 #[test]
 fn gen_u128_from_u64() {
     let mut mock = TestMock::new(counter(1..3));
@@ -79,6 +119,60 @@ fn lim_u128_large() {
     }
 }
 
+#[test]
+fn lim_u128_large_bounds() {
+    for lim in [u128::from(u64::MAX) + 1, 1 << 100, u128::MAX / 3, u128::MAX] {
+        assert_eq!(lim - 1, TestMock::new(fixed(u64::MAX)).next_lim_u128(lim));
+    }
+    // a zero draw is only accepted for powers of two, which have no rejection zone
+    for lim in [u128::from(u64::MAX) + 1, 1 << 100, 1 << 127] {
+        assert_eq!(0, TestMock::new(fixed(0)).next_lim_u128(lim));
+    }
+}
+
+#[test]
+fn lim_u128_large_rejects() {
+    // for a limit just over 2^127, nearly half of the draws are rejected, including zero
+    let lim = (1 << 127) + 1;
+    let mut rand = TestMock::new(|state| if state.next_u64_invocations() < 2 { 0 } else { u64::MAX });
+    assert_eq!(lim - 1, rand.next_lim_u128(lim));
+    assert_eq!(4, rand.state().next_u64_invocations());
+}
+
+#[test]
+fn lim_u128_legacy() {
+    let lim = u128::from(u64::MAX) + 5;
+    let next = 7 << 64 | 7;
+    assert_eq!(next % lim, TestMock::new(fixed(7)).next_lim_u128_legacy(lim));
+    assert_ne!(next % lim, TestMock::new(fixed(7)).next_lim_u128(lim));
+
+    // small limits are unaffected
+    for lim in 1..13u128 {
+        let mut legacy = TestMock::new(counter(u64::MAX - 17..u64::MAX));
+        let mut current = TestMock::new(counter(u64::MAX - 17..u64::MAX));
+        assert_eq!(current.next_lim_u128(lim), legacy.next_lim_u128_legacy(lim));
+    }
+}
+
+#[test]
+#[should_panic(expected="zero limit")]
+fn zero_lim_128_legacy() {
+    TestMock::new(fixed(0)).next_lim_u128_legacy(0);
+}
+
+#[test]
+fn test_wide_mul_u128() {
+    assert_eq!((0, 0), wide_mul_u128(0, u128::MAX));
+    assert_eq!((0, u128::MAX), wide_mul_u128(1, u128::MAX));
+    assert_eq!((1, u128::MAX - 1), wide_mul_u128(2, u128::MAX));
+    assert_eq!((1, 0), wide_mul_u128(1 << 64, 1 << 64));
+    assert_eq!((u128::MAX - 1, 1), wide_mul_u128(u128::MAX, u128::MAX));
+    assert_eq!((1 << 126, 0), wide_mul_u128(1 << 127, 1 << 127));
+    let a = 0x1234_5678_9ABC_DEF0_0FED_CBA9_8765_4321;
+    assert_eq!(wide_mul_u128(a, 3), (0, a * 3));
+    assert_eq!(wide_mul_u128(a, 1 << 100), (a >> 28, a << 100));
+}
+
 pub fn lim_types_max(mut rand: impl Rand) {
     assert_ne!(0, rand.next_lim(u16::MAX));
     assert_ne!(0, rand.next_lim(u32::MAX));
@@ -128,7 +222,7 @@ pub fn random_range_u64(mut rand: impl Rand) {
         exp_min: u64,
         exp_max: u64,
     }
-    for case in &vec![
+    for case in &[
         Case {
             range: 0..100,
             exp_min: 0,
@@ -173,7 +267,7 @@ pub fn random_range_u128(mut rand: impl Rand) {
         exp_min: u128,
         exp_max: u128,
     }
-    for case in &vec![
+    for case in &[
         Case {
             range: 0..100,
             exp_min: 0,
@@ -245,6 +339,39 @@ fn probability_unchecked() {
     }
 }
 
+#[test]
+fn probability_from_ratio() {
+    assert_eq!(Some((1, 3)), Probability::from_ratio(1, 3).ratio());
+    assert_eq!(Some((1, 3)), Probability::from_ratio(33, 99).ratio());
+    assert_eq!(Some((0, 1)), Probability::from_ratio(0, 5).ratio());
+    assert_eq!(Some((1, 1)), Probability::from_ratio(u64::MAX, u64::MAX).ratio());
+    assert_eq!(Probability::from_ratio(2, 4), Probability::from_ratio(1, 2));
+    assert_eq!(None, Probability::new(0.5).ratio());
+    assert_eq!(0.25, f64::from(Probability::from_ratio(1, 4)));
+}
+
+#[test]
+#[should_panic(expected="zero denominator")]
+fn probability_from_ratio_panics_zero_denominator() {
+    Probability::from_ratio(0, 0);
+}
+
+#[test]
+#[should_panic(expected="numerator (4) cannot be greater than denominator (3)")]
+fn probability_from_ratio_panics_gt_1() {
+    Probability::from_ratio(4, 3);
+}
+
+#[test]
+fn next_bool_from_ratio() {
+    let mut rand = TestMock::new(fixed(1));
+    assert!(!rand.next_bool(Probability::from_ratio(0, 3)));
+    assert!(rand.next_bool(Probability::from_ratio(1, 3)));
+    let mut rand = TestMock::new(fixed(u64::MAX));
+    assert!(!rand.next_bool(Probability::from_ratio(2, 3)));
+    assert!(rand.next_bool(Probability::from_ratio(3, 3)));
+}
+
 #[test]
 fn test_cutoff_u128() {
     assert_eq!(u128::MAX, cutoff_u128(1));
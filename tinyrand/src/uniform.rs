@@ -0,0 +1,257 @@
+//! Uniform sampling from a range, with the range validated and the sampling parameters
+//! computed once, upfront.
+
+use crate::duration::from_nanos;
+use crate::{wide_mul_u128, Distribution, Rand};
+use core::fmt::Debug;
+use core::ops::Range;
+use core::time::Duration;
+
+/// The uniform distribution over a half-open range.
+///
+/// [`RandRange::next_range`](crate::RandRange::next_range) validates the range on every call
+/// and, for most limits, recomputes the rejection threshold. A [`Uniform`] does this once, making
+/// it the preferred choice when sampling repeatedly from the same range. For the types supported
+/// by both, the sampled values are identical to those of `next_range` given the same random stream.
+///
+/// All primitive integer types, [`Duration`], `f32` and `f64` are supported.
+///
+/// # Examples
+/// ```
+/// use tinyrand::{Distribution, StdRand, Uniform};
+/// let dist = Uniform::new(-10..10i32);
+/// let mut rand = StdRand::default();
+/// for _ in 0..100 {
+///     assert!((-10..10).contains(&dist.sample(&mut rand)));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Uniform<N: SampleUniform> {
+    start: N,
+    span: N::Span,
+    threshold: N::Span,
+}
+
+/// A type that can be sampled uniformly by way of [`Uniform`].
+pub trait SampleUniform: Copy {
+    /// The representation of the span of the range and of the rejection threshold.
+    type Span: Copy + Debug + PartialEq;
+
+    /// Validates the range, precomputing the parameters for sampling.
+    fn uniform(range: Range<Self>) -> Uniform<Self>;
+
+    /// Samples a value from the given uniform distribution.
+    fn sample_uniform(uniform: &Uniform<Self>, rand: &mut impl Rand) -> Self;
+}
+
+impl<N: SampleUniform> Uniform<N> {
+    /// Creates a new [`Uniform`] distribution over the given range.
+    ///
+    /// # Panics
+    /// If the range is empty. For floating-point types, also if either bound is not finite or if
+    /// the width of the range is not representable.
+    #[inline(always)]
+    pub fn new(range: Range<N>) -> Self {
+        N::uniform(range)
+    }
+
+    /// The (inclusive) start of the range.
+    #[inline(always)]
+    pub fn start(&self) -> N {
+        self.start
+    }
+}
+
+impl<N: SampleUniform> Distribution<N> for Uniform<N> {
+    #[inline(always)]
+    fn sample(&self, rand: &mut impl Rand) -> N {
+        N::sample_uniform(self, rand)
+    }
+}
+
+/// Implements [`SampleUniform`] for an integer type whose span fits in 64 bits, using the
+/// widening multiply of D. Lemire, with the rejection threshold precomputed. `$next` generates a
+/// random `$span`, and `$wide` is twice its width.
+macro_rules! uniform_int {
+    ($t:ty, $unsigned:ty, $span:ty, $wide:ty, $next:ident) => {
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+        impl SampleUniform for $t {
+            type Span = $span;
+
+            #[inline(always)]
+            fn uniform(range: Range<Self>) -> Uniform<Self> {
+                assert!(!range.is_empty(), "empty range");
+                let span = (range.end as $unsigned).wrapping_sub(range.start as $unsigned) as $span;
+                Uniform {
+                    start: range.start,
+                    span,
+                    threshold: span.wrapping_neg() % span,
+                }
+            }
+
+            #[inline(always)]
+            fn sample_uniform(uniform: &Uniform<Self>, rand: &mut impl Rand) -> Self {
+                loop {
+                    let full = <$wide>::from(rand.$next()) * <$wide>::from(uniform.span);
+                    if full as $span >= uniform.threshold {
+                        let offset = (full >> <$span>::BITS) as $unsigned;
+                        return uniform.start.wrapping_add(offset as $t);
+                    }
+                }
+            }
+        }
+    };
+}
+
+uniform_int!(u8, u8, u16, u32, next_u16);
+uniform_int!(i8, u8, u16, u32, next_u16);
+uniform_int!(u16, u16, u16, u32, next_u16);
+uniform_int!(i16, u16, u16, u32, next_u16);
+uniform_int!(u32, u32, u32, u64, next_u32);
+uniform_int!(i32, u32, u32, u64, next_u32);
+uniform_int!(u64, u64, u64, u128, next_u64);
+uniform_int!(i64, u64, u64, u128, next_u64);
+
+#[cfg(target_pointer_width = "16")]
+uniform_int!(usize, u16, u16, u32, next_u16);
+#[cfg(target_pointer_width = "16")]
+uniform_int!(isize, u16, u16, u32, next_u16);
+#[cfg(target_pointer_width = "32")]
+uniform_int!(usize, u32, u32, u64, next_u32);
+#[cfg(target_pointer_width = "32")]
+uniform_int!(isize, u32, u32, u64, next_u32);
+#[cfg(target_pointer_width = "64")]
+uniform_int!(usize, u64, u64, u128, next_u64);
+#[cfg(target_pointer_width = "64")]
+uniform_int!(isize, u64, u64, u128, next_u64);
+
+/// The rejection threshold for sampling in `0..span`, as used by [`sample_u128`].
+#[inline(always)]
+fn threshold_u128(span: u128) -> u128 {
+    if span <= u128::from(u64::MAX) {
+        u128::from((span as u64).wrapping_neg() % span as u64)
+    } else {
+        span.wrapping_neg() % span
+    }
+}
+
+/// Samples in `0..span`, consistently with [`Rand::next_lim_u128`].
+#[inline(always)]
+fn sample_u128(rand: &mut impl Rand, span: u128, threshold: u128) -> u128 {
+    if span <= u128::from(u64::MAX) {
+        loop {
+            let full = u128::from(rand.next_u64()) * span;
+            if full as u64 >= threshold as u64 {
+                return full >> 64;
+            }
+        }
+    } else {
+        loop {
+            let (high, low) = wide_mul_u128(rand.next_u128(), span);
+            if low >= threshold {
+                return high;
+            }
+        }
+    }
+}
+
+/// Implements [`SampleUniform`] for a 128-bit integer type.
+macro_rules! uniform_int_128 {
+    ($t:ty) => {
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+        impl SampleUniform for $t {
+            type Span = u128;
+
+            #[inline(always)]
+            fn uniform(range: Range<Self>) -> Uniform<Self> {
+                assert!(!range.is_empty(), "empty range");
+                let span = (range.end as u128).wrapping_sub(range.start as u128);
+                Uniform {
+                    start: range.start,
+                    span,
+                    threshold: threshold_u128(span),
+                }
+            }
+
+            #[inline(always)]
+            fn sample_uniform(uniform: &Uniform<Self>, rand: &mut impl Rand) -> Self {
+                let offset = sample_u128(rand, uniform.span, uniform.threshold);
+                uniform.start.wrapping_add(offset as $t)
+            }
+        }
+    };
+}
+
+uniform_int_128!(u128);
+uniform_int_128!(i128);
+
+#[cfg(target_pointer_width = "128")]
+uniform_int_128!(usize);
+#[cfg(target_pointer_width = "128")]
+uniform_int_128!(isize);
+
+impl SampleUniform for Duration {
+    type Span = u128;
+
+    #[inline(always)]
+    fn uniform(range: Range<Self>) -> Uniform<Self> {
+        assert!(!range.is_empty(), "empty range");
+        let span = (range.end - range.start).as_nanos();
+        Uniform {
+            start: range.start,
+            span,
+            threshold: threshold_u128(span),
+        }
+    }
+
+    #[inline(always)]
+    fn sample_uniform(uniform: &Uniform<Self>, rand: &mut impl Rand) -> Self {
+        let nanos = sample_u128(rand, uniform.span, uniform.threshold);
+        // spans of up to 584 years fit in a u64, for which the conversion is much cheaper
+        match u64::try_from(nanos) {
+            Ok(nanos) => uniform.start + Duration::from_nanos(nanos),
+            Err(_) => uniform.start + from_nanos(nanos),
+        }
+    }
+}
+
+/// Implements [`SampleUniform`] for a floating-point type. The span is the width of the range,
+/// and the threshold is its (exclusive) end: a value that rounds up to the end is rejected.
+macro_rules! uniform_float {
+    ($t:ty, $next:ident) => {
+        impl SampleUniform for $t {
+            type Span = $t;
+
+            #[inline(always)]
+            fn uniform(range: Range<Self>) -> Uniform<Self> {
+                let Range { start, end } = range;
+                assert!(start.is_finite(), "range start ({start}) must be finite");
+                assert!(end.is_finite(), "range end ({end}) must be finite");
+                assert!(start < end, "empty range");
+                let span = end - start;
+                assert!(span.is_finite(), "range ({start}..{end}) is too wide");
+                Uniform {
+                    start,
+                    span,
+                    threshold: end,
+                }
+            }
+
+            #[inline(always)]
+            fn sample_uniform(uniform: &Uniform<Self>, rand: &mut impl Rand) -> Self {
+                loop {
+                    let value = uniform.start + rand.$next() * uniform.span;
+                    if value < uniform.threshold {
+                        return value;
+                    }
+                }
+            }
+        }
+    };
+}
+
+uniform_float!(f32, next_f32);
+uniform_float!(f64, next_f64);
+
+#[cfg(test)]
+mod tests;
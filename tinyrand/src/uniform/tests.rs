@@ -0,0 +1,147 @@
+use crate::test_mock::{fixed, TestMock};
+use crate::uniform::{SampleUniform, Uniform};
+use crate::{Distribution, RandRange, Seeded, Wyrand};
+use core::fmt::Debug;
+use core::ops::Range;
+use core::time::Duration;
+
+fn assert_matches_next_range<N>(range: Range<N>)
+where
+    N: SampleUniform + Debug + PartialEq,
+    Wyrand: RandRange<N>,
+{
+    let dist = Uniform::new(range.clone());
+    let (mut rand, mut control) = (Wyrand::seed(42), Wyrand::seed(42));
+    for _ in 0..1_000 {
+        assert_eq!(control.next_range(range.clone()), dist.sample(&mut rand), "for {range:?}");
+    }
+}
+
+#[test]
+fn matches_next_range() {
+    for end in [1, 2, 3, 17, u16::MAX / 2 + 1, u16::MAX] {
+        assert_matches_next_range(0..end);
+        assert_matches_next_range(u32::from(end)..u32::MAX);
+        assert_matches_next_range(u64::from(end)..u64::MAX);
+        assert_matches_next_range(u128::from(end)..u128::from(u64::MAX));
+        assert_matches_next_range(u128::from(end)..u128::MAX / 3);
+        assert_matches_next_range(usize::from(end)..usize::MAX / 3);
+        assert_matches_next_range(Duration::from_nanos(u64::from(end))..Duration::from_secs(1));
+        assert_matches_next_range(Duration::ZERO..Duration::MAX / u32::from(end));
+    }
+}
+
+#[test]
+fn integer_bounds() {
+    fn assert_bounds<N: SampleUniform + Debug + PartialEq>(range: Range<N>, last: N) {
+        let dist = Uniform::new(range.clone());
+        assert_eq!(range.start, dist.start());
+        assert_eq!(range.start, dist.sample(&mut TestMock::new(fixed(1))));
+        assert_eq!(last, dist.sample(&mut TestMock::new(fixed(u64::MAX))));
+    }
+    assert_bounds(0..u8::MAX, u8::MAX - 1);
+    assert_bounds(i8::MIN..i8::MAX, i8::MAX - 1);
+    assert_bounds(-3..3i16, 2);
+    assert_bounds(i32::MIN..0, -1);
+    assert_bounds(i64::MIN..i64::MAX, i64::MAX - 1);
+    assert_bounds(-100..100i128, 99);
+    assert_bounds(isize::MIN..isize::MAX, isize::MAX - 1);
+}
+
+#[test]
+fn wide_128_bit_bounds() {
+    // fixed(1) yields 2^64 + 1 from next_u128, which scales to an offset of 2^64
+    let dist = Uniform::new(i128::MIN..i128::MAX);
+    assert_eq!(i128::MIN + (1 << 64), dist.sample(&mut TestMock::new(fixed(1))));
+    assert_eq!(i128::MAX - 1, dist.sample(&mut TestMock::new(fixed(u64::MAX))));
+}
+
+#[test]
+fn singleton_range() {
+    let mut rand = Wyrand::default();
+    assert_eq!(-5, Uniform::new(-5..-4i64).sample(&mut rand));
+    assert_eq!(u128::MAX - 1, Uniform::new(u128::MAX - 1..u128::MAX).sample(&mut rand));
+    assert_eq!(Duration::MAX - Duration::from_nanos(1), Uniform::new(Duration::MAX - Duration::from_nanos(1)..Duration::MAX).sample(&mut rand));
+}
+
+#[test]
+fn signed_within_range() {
+    let mut rand = Wyrand::default();
+    let dist = Uniform::new(-7..5i8);
+    let mut seen = [false; 12];
+    for _ in 0..1_000 {
+        let value = dist.sample(&mut rand);
+        assert!((-7..5).contains(&value), "{value}");
+        seen[(value + 7) as usize] = true;
+    }
+    assert!(seen.iter().all(|&seen| seen));
+}
+
+#[test]
+fn float_bounds() {
+    let dist = Uniform::new(-2.0..3.0);
+    assert_eq!(-2.0, dist.sample(&mut TestMock::new(fixed(0))));
+    let highest = dist.sample(&mut TestMock::new(fixed(u64::MAX)));
+    assert!(highest < 3.0 && highest > 2.999_999, "{highest}");
+
+    let dist = Uniform::new(-2.0..3.0f32);
+    assert_eq!(-2.0, dist.sample(&mut TestMock::new(fixed(0))));
+    let highest = dist.sample(&mut TestMock::new(fixed(u64::MAX)));
+    assert!(highest < 3.0 && highest > 2.999, "{highest}");
+}
+
+#[test]
+fn float_rejects_end() {
+    // the highest draw rounds up to the end of a narrow range, so another is made
+    let end = 1.0 + 2.0 * f64::EPSILON;
+    let dist = Uniform::new(1.0..end);
+    let mut rand = TestMock::new(|state| if state.next_u64_invocations() == 0 { u64::MAX } else { 0 });
+    assert_eq!(1.0, dist.sample(&mut rand));
+    assert_eq!(2, rand.state().next_u64_invocations());
+}
+
+#[test]
+fn float_within_range() {
+    let mut rand = Wyrand::default();
+    let dist = Uniform::new(-1e300..1e300);
+    for _ in 0..1_000 {
+        let value = dist.sample(&mut rand);
+        assert!((-1e300..1e300).contains(&value), "{value}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "empty range")]
+fn panics_on_empty_int_range() {
+    Uniform::new(5..5u32);
+}
+
+#[test]
+#[should_panic(expected = "empty range")]
+fn panics_on_empty_duration_range() {
+    Uniform::new(Duration::from_secs(2)..Duration::from_secs(1));
+}
+
+#[test]
+#[should_panic(expected = "empty range")]
+fn panics_on_empty_float_range() {
+    Uniform::new(1.0..1.0);
+}
+
+#[test]
+#[should_panic(expected = "range start (NaN) must be finite")]
+fn panics_on_nan_start() {
+    Uniform::new(f64::NAN..1.0);
+}
+
+#[test]
+#[should_panic(expected = "range end (inf) must be finite")]
+fn panics_on_infinite_end() {
+    Uniform::new(0.0..f32::INFINITY);
+}
+
+#[test]
+#[should_panic(expected = "is too wide")]
+fn panics_on_wide_float_range() {
+    Uniform::new(f64::MIN..f64::MAX);
+}
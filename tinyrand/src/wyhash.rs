@@ -0,0 +1,93 @@
+//! The [wyhash](https://github.com/wangyi-fudan/wyhash) hash function, from the same lineage as
+//! [`Wyrand`](crate::Wyrand).
+//!
+//! This is the version of wyhash known as "final 4", with its default secret. The implementation
+//! is frozen: for a given input and seed, [`wyhash`] returns the same value on every platform,
+//! and will continue to do so in future versions of this crate. (Should a later version of wyhash
+//! be adopted, it will be added alongside this one, rather than replacing it.)
+
+/// The default secret of wyhash final 4.
+const SECRET: [u64; 4] = [
+    0x2D35_8DCC_AA6C_78A5,
+    0x8BB8_4B93_962E_ACC9,
+    0x4B33_A62E_D433_D4A3,
+    0x4D5A_2DA5_1DE1_AA47,
+];
+
+/// Hashes the given bytes with wyhash final 4.
+///
+/// # Examples
+/// ```
+/// use tinyrand::wyhash::wyhash;
+/// assert_eq!(0xC5BA_C3DB_1787_13C4, wyhash(b"a", 1));
+/// ```
+pub fn wyhash(bytes: &[u8], seed: u64) -> u64 {
+    let len = bytes.len();
+    let mut seed = seed ^ mix(seed ^ SECRET[0], SECRET[1]);
+    let (a, b) = if len <= 16 {
+        if len >= 4 {
+            let quarter = (len >> 3) << 2;
+            (
+                read_u32(bytes, 0) << 32 | read_u32(bytes, quarter),
+                read_u32(bytes, len - 4) << 32 | read_u32(bytes, len - 4 - quarter),
+            )
+        } else if len > 0 {
+            (read_u24(bytes), 0)
+        } else {
+            (0, 0)
+        }
+    } else {
+        let mut rest = bytes;
+        if rest.len() >= 48 {
+            let (mut lane1, mut lane2) = (seed, seed);
+            while rest.len() >= 48 {
+                seed = mix(read_u64(rest, 0) ^ SECRET[1], read_u64(rest, 8) ^ seed);
+                lane1 = mix(read_u64(rest, 16) ^ SECRET[2], read_u64(rest, 24) ^ lane1);
+                lane2 = mix(read_u64(rest, 32) ^ SECRET[3], read_u64(rest, 40) ^ lane2);
+                rest = &rest[48..];
+            }
+            seed ^= lane1 ^ lane2;
+        }
+        while rest.len() > 16 {
+            seed = mix(read_u64(rest, 0) ^ SECRET[1], read_u64(rest, 8) ^ seed);
+            rest = &rest[16..];
+        }
+        // the last 16 bytes of the input, which may overlap those already consumed
+        (read_u64(bytes, len - 16), read_u64(bytes, len - 8))
+    };
+    let (a, b) = mum(a ^ SECRET[1], b ^ seed);
+    mix(a ^ SECRET[0] ^ len as u64, b ^ SECRET[1])
+}
+
+/// The 128-bit product of `a` and `b`, as its lower and upper halves.
+#[inline(always)]
+fn mum(a: u64, b: u64) -> (u64, u64) {
+    let r = u128::from(a) * u128::from(b);
+    (r as u64, (r >> 64) as u64)
+}
+
+#[inline(always)]
+fn mix(a: u64, b: u64) -> u64 {
+    let (low, high) = mum(a, b);
+    low ^ high
+}
+
+#[inline(always)]
+fn read_u64(bytes: &[u8], at: usize) -> u64 {
+    u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap())
+}
+
+#[inline(always)]
+fn read_u32(bytes: &[u8], at: usize) -> u64 {
+    u64::from(u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap()))
+}
+
+/// Reads an input of 1 to 3 bytes.
+#[inline(always)]
+fn read_u24(bytes: &[u8]) -> u64 {
+    let len = bytes.len();
+    u64::from(bytes[0]) << 16 | u64::from(bytes[len >> 1]) << 8 | u64::from(bytes[len - 1])
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,47 @@
+use super::*;
+
+#[test]
+fn reference_vectors() {
+    // from the test vectors of wyhash final 4, each hashed with its index as the seed
+    let cases: [(&[u8], u64); 7] = [
+        (b"", 0x9322_8A4D_E0EE_C5A2),
+        (b"a", 0xC5BA_C3DB_1787_13C4),
+        (b"abc", 0xA97F_2F7B_1D9B_3314),
+        (b"message digest", 0x786D_1F1D_F380_1DF4),
+        (b"abcdefghijklmnopqrstuvwxyz", 0xDCA5_A813_8AD3_7C87),
+        (b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789", 0xB9E7_34F1_17CF_AF70),
+        (b"12345678901234567890123456789012345678901234567890123456789012345678901234567890", 0x6CC5_EAB4_9A92_D617),
+    ];
+    for (seed, (bytes, expected)) in cases.into_iter().enumerate() {
+        assert_eq!(expected, wyhash(bytes, seed as u64), "seed: {seed}");
+    }
+}
+
+#[test]
+fn block_boundaries() {
+    // around the 16- and 48-byte thresholds, and spanning several 48-byte blocks
+    let cases = [
+        (17, 0xD29F_FDD2_01A4_6F9A, 0x597F_668A_EC64_AB78),
+        (47, 0xE2CB_58F6_AB8E_4419, 0x4E1B_EBE5_3A7B_8610),
+        (48, 0xECBF_B7FF_9E3D_9A97, 0x1B07_6A99_3016_C734),
+        (49, 0x0691_F11B_AC52_3A91, 0x3895_13A5_91AE_6FBB),
+        (96, 0x9481_37D6_9794_B570, 0x8AC0_557E_185A_1067),
+        (100, 0x77ED_9A7D_FB9A_C9B7, 0xE625_7F5F_EE9E_F3F2),
+        (200, 0x89B3_8345_F227_3FF6, 0x8AE4_FB30_7314_0E16),
+    ];
+    let data: [u8; 200] = core::array::from_fn(|i| (i % 251) as u8);
+    for (len, unseeded, seeded) in cases {
+        assert_eq!(unseeded, wyhash(&data[..len], 0), "len: {len}");
+        assert_eq!(seeded, wyhash(&data[..len], 0xDEAD_BEEF), "len: {len}");
+    }
+}
+
+#[test]
+fn short_inputs_differ() {
+    let hashes = [b"" as &[u8], b"\0", b"\0\0", b"\0\0\0", b"\0\0\0\0"].map(|bytes| wyhash(bytes, 0));
+    for (i, a) in hashes.iter().enumerate() {
+        for b in &hashes[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+}
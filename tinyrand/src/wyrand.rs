@@ -1,6 +1,7 @@
 //! [Wyrand](https://github.com/wangyi-fudan/wyhash/blob/master/Modern%20Non-Cryptographic%20Hash%20Function%20and%20Pseudorandom%20Number%20Generator.pdf) RNG.
 
-use crate::{Rand, Seeded};
+use crate::lanes::{seed_lanes, RandBlock};
+use crate::{Rand, SeedableFromBytes, Seeded};
 
 #[derive(Default)]
 pub struct Wyrand(u64);
@@ -8,9 +9,7 @@ pub struct Wyrand(u64);
 impl Rand for Wyrand {
     #[inline(always)]
     fn next_u64(&mut self) -> u64 {
-        self.0 = self.0.wrapping_add(0xA076_1D64_78BD_642F);
-        let r = u128::from(self.0) * u128::from(self.0 ^ 0xE703_7ED1_A0B4_28DB);
-        (r as u64) ^ (r >> 64) as u64
+        step(&mut self.0)
     }
 }
 
@@ -23,5 +22,68 @@ impl Seeded for Wyrand {
     }
 }
 
+impl SeedableFromBytes for Wyrand {
+    type Seed = [u8; 8];
+
+    /// Equivalent to [`Seeded::seed`], with the seed read as a little-endian `u64`.
+    #[inline(always)]
+    fn from_seed_bytes(seed: Self::Seed) -> Self {
+        Self::seed(u64::from_le_bytes(seed))
+    }
+}
+
+/// Advances the state of a single Wyrand instance, returning its output.
+#[inline(always)]
+fn step(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0xA076_1D64_78BD_642F);
+    let r = u128::from(*state) * u128::from(*state ^ 0xE703_7ED1_A0B4_28DB);
+    (r as u64) ^ (r >> 64) as u64
+}
+
+/// `L` lanes of [`Wyrand`], generating blocks of `L` random numbers at a time.
+///
+/// (See [`lanes`](crate::lanes) for the order of the outputs.)
+///
+/// # Examples
+/// ```
+/// use tinyrand::{RandBlock, WyrandLanes};
+/// let mut rand = WyrandLanes::<4>::seed(42);
+/// let block: [u64; 4] = rand.next_block();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WyrandLanes<const L: usize>([u64; L]);
+
+impl<const L: usize> WyrandLanes<L> {
+    /// Creates a new [`WyrandLanes`] from the given seed. Lane `i` is equivalent to a [`Wyrand`]
+    /// seeded with the `i`-th output of [`SplitMix`](crate::SplitMix) seeded with `seed`.
+    ///
+    /// # Panics
+    /// If `L` is zero.
+    pub fn seed(seed: u64) -> Self {
+        Self(seed_lanes(seed))
+    }
+
+    /// Creates a new [`WyrandLanes`] from the given seeds, whereby lane `i` is equivalent to
+    /// a [`Wyrand`] seeded with `seeds[i]`.
+    ///
+    /// # Panics
+    /// If `L` is zero.
+    pub fn from_seeds(seeds: [u64; L]) -> Self {
+        assert_ne!(0, L, "zero lanes");
+        Self(seeds)
+    }
+}
+
+impl<const L: usize> RandBlock<L> for WyrandLanes<L> {
+    #[inline(always)]
+    fn next_block(&mut self) -> [u64; L] {
+        let mut block = [0; L];
+        for (state, random) in self.0.iter_mut().zip(block.iter_mut()) {
+            *random = step(state);
+        }
+        block
+    }
+}
+
 #[cfg(test)]
-mod tests;
\ No newline at end of file
+mod tests;
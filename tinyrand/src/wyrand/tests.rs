@@ -1,4 +1,4 @@
-use crate::{Rand, Seeded, Wyrand};
+use crate::{Rand, RandBlock, Seeded, SplitMix, Wyrand, WyrandLanes};
 use crate::duration::tests::random_duration;
 use crate::tests::{lim_types_max, next_types, numbers_differ, random_range_u128, random_range_u64, range_types_max};
 
@@ -52,4 +52,35 @@ fn random_duration_wyrand() {
 fn range_types_max_wyrand() { range_types_max(Wyrand::default()); }
 
 #[test]
-fn numbers_differ_wyrand() { numbers_differ(Wyrand::default()) }
\ No newline at end of file
+fn numbers_differ_wyrand() { numbers_differ(Wyrand::default()) }
+
+#[test]
+fn lanes_match_scalar() {
+    let mut lanes = WyrandLanes::<4>::seed(42);
+    let mut splitmix = SplitMix::seed(42);
+    let mut scalars = [(); 4].map(|_| Wyrand::seed(splitmix.next_u64()));
+    for _ in 0..100 {
+        let block = lanes.next_block();
+        for (scalar, random) in scalars.iter_mut().zip(block) {
+            assert_eq!(scalar.next_u64(), random);
+        }
+    }
+}
+
+#[test]
+fn lanes_from_seeds() {
+    let mut lanes = WyrandLanes::from_seeds([0, 1]);
+    let (mut first, mut second) = (Wyrand::seed(0), Wyrand::seed(1));
+    assert_eq!([first.next_u64(), second.next_u64()], lanes.next_block());
+}
+
+#[test]
+#[should_panic(expected = "zero lanes")]
+fn lanes_panic_zero() {
+    WyrandLanes::<0>::from_seeds([]);
+}
+
+#[test]
+fn seeding_wyrand() {
+    crate::tests::seeding_u64::<Wyrand>();
+}
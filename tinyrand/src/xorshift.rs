@@ -1,6 +1,6 @@
 //! [Xorshift](https://en.wikipedia.org/wiki/Xorshift) RNG.
 
-use crate::{Rand, Seeded};
+use crate::{Rand, SeedableFromBytes, Seeded};
 
 pub struct Xorshift(u64);
 
@@ -33,5 +33,15 @@ impl Seeded for Xorshift {
     }
 }
 
+impl SeedableFromBytes for Xorshift {
+    type Seed = [u8; 8];
+
+    /// Equivalent to [`Seeded::seed`], with the seed read as a little-endian `u64`.
+    #[inline(always)]
+    fn from_seed_bytes(seed: Self::Seed) -> Self {
+        Self::seed(u64::from_le_bytes(seed))
+    }
+}
+
 #[cfg(test)]
 mod tests;
\ No newline at end of file
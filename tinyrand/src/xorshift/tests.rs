@@ -68,4 +68,9 @@ fn random_duration_xorshift() {
 fn range_types_max_xorshift() { range_types_max(Xorshift::default()); }
 
 #[test]
-fn numbers_differ_xorshift() { numbers_differ(Xorshift::default()) }
\ No newline at end of file
+fn numbers_differ_xorshift() { numbers_differ(Xorshift::default()) }
+
+#[test]
+fn seeding_xorshift() {
+    crate::tests::seeding_u64::<Xorshift>();
+}
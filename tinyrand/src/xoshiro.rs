@@ -0,0 +1,170 @@
+//! [Xoshiro256++](https://prng.di.unimi.it/) RNG.
+
+use crate::lanes::{seed_lanes, RandBlock};
+use crate::{Rand, SeedableFromBytes, Seeded, SplitMix};
+
+/// The xoshiro256++ generator of D. Blackman and S. Vigna, having 256 bits of state.
+///
+/// The state is expanded from a 64-bit seed using [`SplitMix`], as its
+/// authors recommend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Xoshiro([u64; 4]);
+
+impl Default for Xoshiro {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::seed(0)
+    }
+}
+
+impl Rand for Xoshiro {
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        let [s0, s1, s2, s3] = &mut self.0;
+        step(s0, s1, s2, s3)
+    }
+}
+
+impl Seeded for Xoshiro {
+    type R = Xoshiro;
+
+    #[inline(always)]
+    fn seed(seed: u64) -> Self::R {
+        // SplitMix never emits zero, so the (invalid) all-zero state cannot arise
+        Self(seed_lanes(seed))
+    }
+
+    /// Creates a new [`Xoshiro`] from a 128-bit seed. The first two words of state are the first
+    /// two outputs of [`SplitMix`] seeded with the lower half of the seed; the last two are
+    /// likewise derived from the upper half.
+    #[inline(always)]
+    fn seed_u128(seed: u128) -> Self::R {
+        let [s0, s1] = seed_lanes(seed as u64);
+        let [s2, s3] = seed_lanes((seed >> 64) as u64);
+        Self([s0, s1, s2, s3])
+    }
+
+    /// Creates a new [`Xoshiro`], taking its four words of state from the given [`Rand`].
+    #[inline(always)]
+    fn from_rand<G: Rand + ?Sized>(rand: &mut G) -> Self::R {
+        Self::from_state([rand.next_u64(), rand.next_u64(), rand.next_u64(), rand.next_u64()])
+    }
+}
+
+impl SeedableFromBytes for Xoshiro {
+    type Seed = [u8; 32];
+
+    /// Creates a new [`Xoshiro`] whose state is the given seed, read as four little-endian words.
+    /// An all-zero seed, which xoshiro cannot use, is replaced with the state of
+    /// [`Xoshiro::seed(0)`](Seeded::seed).
+    #[inline(always)]
+    fn from_seed_bytes(seed: Self::Seed) -> Self {
+        let mut state = [0; 4];
+        for (word, bytes) in state.iter_mut().zip(seed.chunks_exact(8)) {
+            *word = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        Self::from_state(state)
+    }
+}
+
+impl Xoshiro {
+    /// Uses the given state, unless it is all-zero.
+    #[inline(always)]
+    fn from_state(state: [u64; 4]) -> Self {
+        if state == [0; 4] {
+            Self::seed(0)
+        } else {
+            Self(state)
+        }
+    }
+}
+
+/// Advances the state of a single xoshiro256++ instance, returning its output.
+#[inline(always)]
+fn step(s0: &mut u64, s1: &mut u64, s2: &mut u64, s3: &mut u64) -> u64 {
+    let result = s0.wrapping_add(*s3).rotate_left(23).wrapping_add(*s0);
+    let t = *s1 << 17;
+    *s2 ^= *s0;
+    *s3 ^= *s1;
+    *s1 ^= *s2;
+    *s0 ^= *s3;
+    *s2 ^= t;
+    *s3 = s3.rotate_left(45);
+    result
+}
+
+/// `L` lanes of [`Xoshiro`], generating blocks of `L` random numbers at a time.
+///
+/// The states are stored by word, rather than by lane, so that each step of the algorithm
+/// updates a contiguous array. (See [`lanes`](crate::lanes) for the order of the outputs.)
+///
+/// # Examples
+/// ```
+/// use tinyrand::{RandBlock, XoshiroLanes};
+/// let mut rand = XoshiroLanes::<8>::seed(42);
+/// let mut samples = vec![0u64; 1_000];
+/// rand.fill_u64(&mut samples);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XoshiroLanes<const L: usize>([[u64; L]; 4]);
+
+impl<const L: usize> XoshiroLanes<L> {
+    /// Creates a new [`XoshiroLanes`] from the given seed. Lane `i` is seeded with words `4i`
+    /// to `4i + 3` of the [`SplitMix`] stream used by [`Xoshiro::seed`]; hence,
+    /// lane 0 produces the same sequence as a [`Xoshiro`] with the same seed.
+    ///
+    /// # Panics
+    /// If `L` is zero.
+    pub fn seed(seed: u64) -> Self {
+        assert_ne!(0, L, "zero lanes");
+        let mut state = [[0; L]; 4];
+        // the SplitMix stream is consumed by lane, four words at a time
+        let mut splitmix = SplitMix::seed(seed);
+        for lane in 0..L {
+            for word in &mut state {
+                word[lane] = splitmix.next_u64();
+            }
+        }
+        Self(state)
+    }
+}
+
+impl<const L: usize> RandBlock<L> for XoshiroLanes<L> {
+    #[inline(always)]
+    #[allow(clippy::needless_range_loop)]
+    fn next_block(&mut self) -> [u64; L] {
+        // each operation of `step` is applied to all lanes before the next, so that every
+        // loop is over contiguous arrays
+        let [s0, s1, s2, s3] = &mut self.0;
+        let mut block = [0; L];
+        for lane in 0..L {
+            block[lane] = s0[lane].wrapping_add(s3[lane]).rotate_left(23).wrapping_add(s0[lane]);
+        }
+        let mut t = [0; L];
+        for lane in 0..L {
+            t[lane] = s1[lane] << 17;
+        }
+        for lane in 0..L {
+            s2[lane] ^= s0[lane];
+        }
+        for lane in 0..L {
+            s3[lane] ^= s1[lane];
+        }
+        for lane in 0..L {
+            s1[lane] ^= s2[lane];
+        }
+        for lane in 0..L {
+            s0[lane] ^= s3[lane];
+        }
+        for lane in 0..L {
+            s2[lane] ^= t[lane];
+        }
+        for lane in 0..L {
+            s3[lane] = s3[lane].rotate_left(45);
+        }
+        block
+    }
+}
+
+#[cfg(test)]
+mod tests;
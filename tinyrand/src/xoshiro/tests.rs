@@ -0,0 +1,128 @@
+use crate::duration::tests::random_duration;
+use crate::lanes::RandBlock;
+use crate::tests::{lim_types_max, next_types, numbers_differ, random_range_u128, random_range_u64, range_types_max};
+use crate::test_mock::{fixed, TestMock};
+use crate::xoshiro::{Xoshiro, XoshiroLanes};
+use crate::{Counter, Rand, SeedableFromBytes, Seeded, SplitMix};
+
+#[test]
+fn create_default() {
+    let mut rand = Xoshiro::default();
+    assert_eq!(Xoshiro::seed(0), rand);
+    assert_ne!(0, rand.next_u64());
+}
+
+#[test]
+fn create_seeded() {
+    for seed in [0, 1, u64::MAX] {
+        let rand = Xoshiro::seed(seed);
+        let mut splitmix = SplitMix::seed(seed);
+        assert_eq!([(); 4].map(|_| splitmix.next_u64()), rand.0);
+    }
+}
+
+#[test]
+fn reference_sequence() {
+    // the first outputs of the reference implementation, from the state {1, 2, 3, 4}
+    let mut rand = Xoshiro([1, 2, 3, 4]);
+    let expected = [
+        41_943_041,
+        58_720_359,
+        3_588_806_011_781_223,
+        3_591_011_842_654_386,
+        9_228_616_714_210_784_205,
+        9_973_669_472_204_895_162,
+        14_011_001_112_246_962_877,
+        12_406_186_145_184_390_807,
+        15_849_039_046_786_891_736,
+        10_450_023_813_501_588_000,
+    ];
+    for expected in expected {
+        assert_eq!(expected, rand.next_u64());
+    }
+}
+
+#[test]
+fn lanes_match_scalar() {
+    let mut lanes = XoshiroLanes::<3>::seed(42);
+    let mut splitmix = SplitMix::seed(42);
+    let mut scalars = [(); 3].map(|_| Xoshiro([(); 4].map(|_| splitmix.next_u64())));
+    assert_eq!(Xoshiro::seed(42), scalars[0]);
+    for _ in 0..100 {
+        let block = lanes.next_block();
+        for (scalar, random) in scalars.iter_mut().zip(block) {
+            assert_eq!(scalar.next_u64(), random);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "zero lanes")]
+fn lanes_panic_zero() {
+    XoshiroLanes::<0>::seed(0);
+}
+
+#[test]
+fn next_types_xoshiro() {
+    next_types(Xoshiro::default());
+}
+
+#[test]
+fn lim_types_max_xoshiro() {
+    lim_types_max(Xoshiro::default());
+}
+
+#[test]
+fn random_range_u64_xoshiro() {
+    random_range_u64(Xoshiro::default());
+}
+
+#[test]
+fn random_range_u128_xoshiro() {
+    random_range_u128(Xoshiro::default());
+}
+
+#[test]
+fn random_duration_xoshiro() {
+    random_duration(Xoshiro::default());
+}
+
+#[test]
+fn range_types_max_xoshiro() { range_types_max(Xoshiro::default()); }
+
+#[test]
+fn numbers_differ_xoshiro() { numbers_differ(Xoshiro::default()) }
+
+#[test]
+fn from_seed_bytes() {
+    let mut seed = [0; 32];
+    seed[0] = 1;
+    seed[8] = 2;
+    seed[16] = 3;
+    seed[31] = 4;
+    assert_eq!([1, 2, 3, 4 << 56], Xoshiro::from_seed_bytes(seed).0);
+}
+
+#[test]
+fn from_zero_seed_bytes() {
+    assert_eq!(Xoshiro::seed(0), Xoshiro::from_seed_bytes([0; 32]));
+}
+
+#[test]
+fn seed_u128() {
+    let seed = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210;
+    let mut low = SplitMix::seed(0xFEDC_BA98_7654_3210);
+    let mut high = SplitMix::seed(0x0123_4567_89AB_CDEF);
+    assert_eq!(
+        [low.next_u64(), low.next_u64(), high.next_u64(), high.next_u64()],
+        Xoshiro::seed_u128(seed).0
+    );
+    assert_ne!(Xoshiro::seed_u128(seed), Xoshiro::seed_u128(seed ^ 1 << 64));
+    assert_ne!([0; 4], Xoshiro::seed_u128(0).0);
+}
+
+#[test]
+fn from_rand() {
+    assert_eq!([5, 6, 7, 8], Xoshiro::from_rand(&mut Counter::new(5)).0);
+    assert_eq!(Xoshiro::seed(0), Xoshiro::from_rand(&mut TestMock::new(fixed(0))));
+}
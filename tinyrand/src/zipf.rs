@@ -0,0 +1,180 @@
+//! Power-law distributions over bounded integer ranges, including [Zipf's law](https://en.wikipedia.org/wiki/Zipf%27s_law).
+//!
+//! Sampling uses the [rejection-inversion](https://dl.acm.org/doi/10.1145/235025.235029) method
+//! of W. Hörmann and G. Derflinger. It needs neither lookup tables nor the normalisation constant
+//! of the distribution, so the setup cost and memory footprint are constant, irrespective of the
+//! size of the range. Ranges as wide as `u64::MAX` are supported.
+
+use crate::{Distribution, Rand};
+use core::ops::Range;
+
+/// A discrete power-law distribution over a bounded range of positive integers, such that the
+/// probability of sampling _k_ is proportional to _k_<sup>-_s_</sup>, where _s_ is the exponent.
+///
+/// # Examples
+/// ```
+/// use tinyrand::{Distribution, PowerLaw, StdRand};
+/// let dist = PowerLaw::new(10..1_000, 1.5);
+/// let mut rand = StdRand::default();
+/// let k = dist.sample(&mut rand);
+/// assert!((10..1_000).contains(&k));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PowerLaw {
+    first: u64,
+    last: u64,
+    exponent: f64,
+    h_integral_first: f64,
+    h_integral_last: f64,
+    squeeze: f64,
+}
+
+impl PowerLaw {
+    /// Creates a new [`PowerLaw`] distribution over the given range, with the given exponent.
+    ///
+    /// # Panics
+    /// If the range is empty or starts at zero, or if `exponent` is negative or not finite.
+    pub fn new(range: Range<u64>, exponent: f64) -> Self {
+        assert!(!range.is_empty(), "empty range");
+        Self::bounded(range.start, range.end - 1, exponent)
+    }
+
+    /// Creates a new [`PowerLaw`] distribution over the inclusive range `first..=last`.
+    fn bounded(first: u64, last: u64, exponent: f64) -> Self {
+        assert_ne!(0, first, "range cannot start at zero");
+        assert!(exponent >= 0f64, "exponent ({exponent}) cannot be less than 0");
+        assert!(exponent.is_finite(), "exponent ({exponent}) must be finite");
+
+        #[allow(clippy::cast_precision_loss)]
+        let (first_f, last_f) = (first as f64, last as f64);
+        let h_integral_first = h_integral(first_f + 0.5, exponent) - h(first_f, exponent);
+        let h_integral_last = h_integral(last_f + 0.5, exponent);
+        let squeeze = first_f + 1.0
+            - h_integral_inverse(
+                h_integral(first_f + 1.5, exponent) - h(first_f + 1.0, exponent),
+                exponent,
+            );
+        Self {
+            first,
+            last,
+            exponent,
+            h_integral_first,
+            h_integral_last,
+            squeeze,
+        }
+    }
+
+    /// The exponent of the distribution.
+    pub fn exponent(&self) -> f64 {
+        self.exponent
+    }
+}
+
+impl Distribution<u64> for PowerLaw {
+    fn sample(&self, rand: &mut impl Rand) -> u64 {
+        loop {
+            // u is uniformly distributed in (h_integral_first, h_integral_last]
+            let u = self.h_integral_last + rand.next_f64() * (self.h_integral_first - self.h_integral_last);
+            let x = h_integral_inverse(u, self.exponent);
+
+            // the float-to-int conversion saturates, clamping large values of x to u64::MAX
+            #[allow(clippy::cast_possible_truncation)]
+            #[allow(clippy::cast_sign_loss)]
+            let k = ((x + 0.5) as u64).clamp(self.first, self.last);
+
+            #[allow(clippy::cast_precision_loss)]
+            let k_f = k as f64;
+            if k_f - x <= self.squeeze || u >= h_integral(k_f + 0.5, self.exponent) - h(k_f, self.exponent) {
+                return k;
+            }
+        }
+    }
+}
+
+/// The [Zipf distribution](https://en.wikipedia.org/wiki/Zipf%27s_law) over the ranks `1..=n`,
+/// such that the probability of sampling rank _k_ is proportional to _k_<sup>-_s_</sup>.
+///
+/// This is a special case of [`PowerLaw`], which is useful for modelling the skewed popularity of
+/// keys in caches, databases, and so forth.
+///
+/// # Examples
+/// ```
+/// use tinyrand::{Distribution, StdRand, Zipf};
+/// let dist = Zipf::new(u64::MAX, 1.1);
+/// let mut rand = StdRand::default();
+/// let rank = dist.sample(&mut rand);
+/// assert_ne!(0, rank);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Zipf(PowerLaw);
+
+impl Zipf {
+    /// Creates a new [`Zipf`] distribution over `n` elements, with the exponent `s`.
+    ///
+    /// # Panics
+    /// If `n` is zero, or if `s` is negative or not finite.
+    pub fn new(n: u64, s: f64) -> Self {
+        assert_ne!(0, n, "n cannot be zero");
+        Self(PowerLaw::bounded(1, n, s))
+    }
+
+    /// The number of elements.
+    pub fn n(&self) -> u64 {
+        self.0.last
+    }
+
+    /// The exponent of the distribution.
+    pub fn s(&self) -> f64 {
+        self.0.exponent
+    }
+}
+
+impl Distribution<u64> for Zipf {
+    #[inline(always)]
+    fn sample(&self, rand: &mut impl Rand) -> u64 {
+        self.0.sample(rand)
+    }
+}
+
+/// _h_(_x_) = _x_<sup>-_s_</sup>.
+#[inline(always)]
+fn h(x: f64, exponent: f64) -> f64 {
+    libm::exp(-exponent * libm::log(x))
+}
+
+/// _H_(_x_), being the integral of _h_(_x_), such that _H_(1) = 0.
+#[inline(always)]
+fn h_integral(x: f64, exponent: f64) -> f64 {
+    let log_x = libm::log(x);
+    expm1_over_x((1.0 - exponent) * log_x) * log_x
+}
+
+/// The inverse of _H_(_x_).
+#[inline(always)]
+fn h_integral_inverse(x: f64, exponent: f64) -> f64 {
+    let t = (x * (1.0 - exponent)).max(-1.0);
+    libm::exp(log1p_over_x(t) * x)
+}
+
+/// Computes `ln(1 + x) / x`, with a Taylor expansion near zero.
+#[inline(always)]
+fn log1p_over_x(x: f64) -> f64 {
+    if x.abs() > 1e-8 {
+        libm::log1p(x) / x
+    } else {
+        1.0 - x * (0.5 - x * (1.0 / 3.0 - 0.25 * x))
+    }
+}
+
+/// Computes `(exp(x) - 1) / x`, with a Taylor expansion near zero.
+#[inline(always)]
+fn expm1_over_x(x: f64) -> f64 {
+    if x.abs() > 1e-8 {
+        libm::expm1(x) / x
+    } else {
+        1.0 + x * 0.5 * (1.0 + x / 3.0 * (1.0 + 0.25 * x))
+    }
+}
+
+#[cfg(test)]
+mod tests;
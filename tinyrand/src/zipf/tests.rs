@@ -0,0 +1,95 @@
+use crate::test_mock::{counter, fixed, TestMock};
+use crate::zipf::{expm1_over_x, log1p_over_x, PowerLaw, Zipf};
+use crate::{Distribution, Wyrand};
+
+#[test]
+fn zipf_within_range() {
+    let mut rand = Wyrand::default();
+    for n in [1, 2, 3, 10, 1_000, u64::MAX >> 1, u64::MAX] {
+        for s in [0.0, 0.5, 1.0, 1.5, 3.0] {
+            let dist = Zipf::new(n, s);
+            assert_eq!(n, dist.n());
+            assert_eq!(s, dist.s());
+            for _ in 0..100 {
+                let k = dist.sample(&mut rand);
+                assert!(k >= 1, "for n={n}, s={s}, sampled {k}");
+                assert!(k <= n, "for n={n}, s={s}, sampled {k}");
+            }
+        }
+    }
+}
+
+#[test]
+fn power_law_within_range() {
+    let mut rand = Wyrand::default();
+    for range in [1..2, 5..6, 5..7, 100..1_000, u64::MAX - 10..u64::MAX] {
+        for exponent in [0.0, 0.5, 1.0, 1.5, 3.0] {
+            let dist = PowerLaw::new(range.clone(), exponent);
+            assert_eq!(exponent, dist.exponent());
+            for _ in 0..100 {
+                let k = dist.sample(&mut rand);
+                assert!(range.contains(&k), "for range={range:?}, exponent={exponent}, sampled {k}");
+            }
+        }
+    }
+}
+
+#[test]
+fn zipf_extremes_of_unit_interval() {
+    // the smallest random number maps to the largest rank, and vice versa
+    let dist = Zipf::new(100, 1.0);
+    assert_eq!(100, dist.sample(&mut TestMock::new(fixed(0))));
+    assert_eq!(1, dist.sample(&mut TestMock::new(fixed(u64::MAX))));
+}
+
+#[test]
+fn zipf_rejects_and_retries() {
+    // cycles through the unit interval in coarse steps, exercising the rejection branch
+    let dist = Zipf::new(10, 2.0);
+    let mut steps = counter(0..16u64);
+    let mut rand = TestMock::new(move |state| (steps(state) << 60) | 0x0FFF_FFFF_FFFF_FFFF);
+    for _ in 0..32 {
+        let k = dist.sample(&mut rand);
+        assert!((1..=10).contains(&k));
+    }
+}
+
+#[test]
+#[should_panic(expected = "n cannot be zero")]
+fn zipf_zero_n() {
+    Zipf::new(0, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "cannot be less than 0")]
+fn zipf_negative_exponent() {
+    Zipf::new(10, -f64::EPSILON);
+}
+
+#[test]
+#[should_panic(expected = "must be finite")]
+fn zipf_infinite_exponent() {
+    Zipf::new(10, f64::INFINITY);
+}
+
+#[test]
+#[should_panic(expected = "empty range")]
+fn power_law_empty_range() {
+    PowerLaw::new(10..10, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "range cannot start at zero")]
+fn power_law_from_zero() {
+    PowerLaw::new(0..10, 1.0);
+}
+
+#[test]
+fn taylor_expansions_continuous_at_threshold() {
+    for x in [1e-8, -1e-8] {
+        assert!((log1p_over_x(x) - log1p_over_x(x * 1.000_001)).abs() < 1e-12);
+        assert!((expm1_over_x(x) - expm1_over_x(x * 1.000_001)).abs() < 1e-12);
+    }
+    assert_eq!(1.0, log1p_over_x(0.0));
+    assert_eq!(1.0, expm1_over_x(0.0));
+}
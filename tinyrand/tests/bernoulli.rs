@@ -0,0 +1,128 @@
+//! Conducts a series of Bernoulli trials on a [`Rand`] with a different (randomly chosen)
+//! probability on each trial, using exact ratios and precomputed [`Bernoulli`] samplers. Within
+//! each trial, H0 asserts that the source is random. (I.e., the number of successes falls within
+//! a statistically acceptable interval.)
+
+pub mod stats;
+
+use crate::stats::{holm_bonferroni_seq_correction, integrate_binomial, Rejection};
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+use tinyrand::{Bernoulli, Counter, Distribution, Probability, Rand, Seeded, SplitMix, Wyrand, Xorshift};
+
+#[test]
+fn ratio_splitmix() {
+    ratio::<SplitMix>(Options::default()).unwrap();
+}
+
+#[test]
+fn ratio_wyrand() {
+    ratio::<Wyrand>(Options::default()).unwrap();
+}
+
+#[test]
+fn ratio_xorshift() {
+    ratio::<Xorshift>(Options::default()).unwrap();
+}
+
+#[test]
+fn ratio_counter_should_reject() {
+    assert!(ratio::<Counter>(Options::default()).is_err());
+}
+
+#[test]
+fn bernoulli_splitmix() {
+    bernoulli::<SplitMix>(Options::default()).unwrap();
+}
+
+#[test]
+fn bernoulli_wyrand() {
+    bernoulli::<Wyrand>(Options::default()).unwrap();
+}
+
+#[test]
+fn bernoulli_xorshift() {
+    bernoulli::<Xorshift>(Options::default()).unwrap();
+}
+
+#[test]
+fn bernoulli_counter_should_reject() {
+    assert!(bernoulli::<Counter>(Options::default()).is_err());
+}
+
+/// Options for conducting multiple trials.
+#[derive(Debug)]
+pub struct Options {
+    /// Number of randomised trials.
+    pub trials: u16,
+
+    // Experiments per trial.
+    pub iters: u16,
+
+    // Largest denominator of the sampled ratios.
+    pub max_denominator: u64,
+
+    // Significance level to reject H0 (stream is random). The higher the significance level, the more likely
+    // H1 (stream is nonrandom) is accepted.
+    pub significance_level: f64,
+}
+
+impl Options {
+    /// Checks that the options are valid.
+    pub fn validate(&self) {
+        assert!(self.trials > 0);
+        assert!(self.iters > 0);
+        assert!(self.max_denominator > 0);
+        assert!(self.significance_level >= f64::EPSILON);
+        assert!(self.significance_level <= 1.0 - f64::EPSILON);
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            trials: 1000,
+            iters: 30,
+            max_denominator: 1000,
+            significance_level: 0.25,
+        }
+    }
+}
+
+fn ratio<S: Seeded>(opts: Options) -> Result<(), Vec<Rejection>> {
+    opts.validate();
+    let mut control_rng = StdRng::seed_from_u64(0);
+
+    holm_bonferroni_seq_correction(opts.significance_level, opts.trials, || {
+        let seed = control_rng.next_u64();
+        let mut rand = S::seed(seed);
+        let denominator = control_rng.gen_range(1..=opts.max_denominator);
+        let numerator = control_rng.gen_range(0..=denominator);
+        let prob = Probability::from_ratio(numerator, denominator);
+        let successes = (0..opts.iters).filter(|_| rand.next_bool(prob)).count() as u16;
+        let run_within_prob = integrate_binomial(opts.iters, numerator as f64 / denominator as f64, successes);
+        1.0 - run_within_prob
+    })
+}
+
+fn bernoulli<S: Seeded>(opts: Options) -> Result<(), Vec<Rejection>> {
+    opts.validate();
+    let mut control_rng = StdRng::seed_from_u64(0);
+
+    holm_bonferroni_seq_correction(opts.significance_level, opts.trials, || {
+        let seed = control_rng.next_u64();
+        let mut rand = S::seed(seed);
+        let weight = generate_weight_for_test(&mut control_rng);
+        let dist = Bernoulli::new(Probability::new(weight));
+        let successes = (0..opts.iters).filter(|_| dist.sample(&mut rand)).count() as u16;
+        let run_within_prob = integrate_binomial(opts.iters, weight, successes);
+        1.0 - run_within_prob
+    })
+}
+
+fn generate_weight_for_test(rng: &mut StdRng) -> f64 {
+    let p = rng.next_u64() as f64 / u64::MAX as f64;
+    assert!(p >= 0.0);
+    assert!(p <= 1.0);
+    p
+}
@@ -7,7 +7,7 @@ pub mod stats;
 use crate::stats::{holm_bonferroni_seq_correction, integrate_binomial, Rejection};
 use rand::rngs::StdRng;
 use rand::{RngCore, SeedableRng};
-use tinyrand::{Counter, Rand, RandRange, Seeded, SplitMix, Wyrand, Xorshift};
+use tinyrand::{Counter, Rand, RandRange, Seeded, SplitMix, Wyrand, Xorshift, Xoshiro};
 
 #[test]
 fn bit_flip_splitmix() {
@@ -24,6 +24,11 @@ fn bit_flip_xorshift() {
     bit_flip::<Xorshift>(Options::default()).unwrap();
 }
 
+#[test]
+fn bit_flip_xoshiro() {
+    bit_flip::<Xoshiro>(Options::default()).unwrap();
+}
+
 #[test]
 fn bit_flip_counter_should_reject() {
     assert!(bit_flip::<Counter>(Options::default()).is_err());
@@ -106,7 +111,6 @@ where
             }
         }
         let run_within_prob = integrate_binomial(opts.iters, 0.5, set_bits);
-        let p_value = 1.0 - run_within_prob;
-        p_value
+        1.0 - run_within_prob
     })
 }
\ No newline at end of file
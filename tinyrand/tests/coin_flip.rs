@@ -8,7 +8,7 @@ pub mod stats;
 use crate::stats::{holm_bonferroni_seq_correction, integrate_binomial, Rejection};
 use rand::rngs::StdRng;
 use rand::{RngCore, SeedableRng};
-use tinyrand::{Counter, Probability, Rand, RandRange, Seeded, SplitMix, Wyrand, Xorshift};
+use tinyrand::{Counter, Probability, Rand, RandRange, Seeded, SplitMix, Wyrand, Xorshift, Xoshiro};
 
 #[test]
 fn coin_flip_splitmix() {
@@ -25,6 +25,11 @@ fn coin_flip_xorshift() {
     coin_flip::<Xorshift>(Options::default()).unwrap();
 }
 
+#[test]
+fn coin_flip_xoshiro() {
+    coin_flip::<Xoshiro>(Options::default()).unwrap();
+}
+
 #[test]
 fn coin_flip_counter_should_reject() {
     assert!(coin_flip::<Counter>(Options::default()).is_err());
@@ -83,8 +88,7 @@ where
             }
         }
         let run_within_prob = integrate_binomial(opts.iters, weight, heads);
-        let p_value = 1.0 - run_within_prob;
-        p_value
+        1.0 - run_within_prob
     })
 }
 
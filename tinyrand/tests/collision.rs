@@ -9,7 +9,7 @@ pub mod stats;
 use rand::rngs::StdRng;
 use rand::{Rng, RngCore, SeedableRng};
 use std::ops::Range;
-use tinyrand::{Counter, RandRange, Seeded, SplitMix, Wyrand, Xorshift};
+use tinyrand::{Counter, RandRange, Seeded, SplitMix, Wyrand, Xorshift, Xoshiro};
 use crate::stats::{holm_bonferroni_seq_correction, integrate_poisson, Rejection};
 
 #[test]
@@ -27,6 +27,11 @@ fn collision_xorshift() {
     collision::<Xorshift>(Options::default()).unwrap();
 }
 
+#[test]
+fn collision_xoshiro() {
+    collision::<Xoshiro>(Options::default()).unwrap();
+}
+
 #[test]
 fn collision_counter_should_reject() {
     assert!(collision::<Counter>(Options::default()).is_err());
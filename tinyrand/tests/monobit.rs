@@ -10,7 +10,7 @@ pub mod stats;
 use crate::stats::{holm_bonferroni_seq_correction, integrate_binomial, Rejection};
 use rand::rngs::StdRng;
 use rand::{RngCore, SeedableRng};
-use tinyrand::{Counter, Rand, RandRange, Seeded, SplitMix, Wyrand, Xorshift};
+use tinyrand::{Counter, Rand, RandRange, Seeded, SplitMix, Wyrand, Xorshift, Xoshiro};
 
 #[test]
 fn monobit_splitmix() {
@@ -27,6 +27,11 @@ fn monobit_xorshift() {
     monobit::<Xorshift>(Options::default()).unwrap();
 }
 
+#[test]
+fn monobit_xoshiro() {
+    monobit::<Xoshiro>(Options::default()).unwrap();
+}
+
 #[test]
 fn monobit_counter_should_reject() {
     assert!(monobit::<Counter>(Options::default()).is_err());
@@ -73,7 +78,7 @@ where
     holm_bonferroni_seq_correction(opts.significance_level, opts.cycles * 2, || {
         let seed = control_rng.next_u64();
         let mut rand = S::seed(seed);
-        let word = if trial % 1 == 0 {
+        let word = if trial % 2 == 0 {
             rand.next_u64() as u32
         } else {
             (rand.next_u64() >> 32) as u32
@@ -81,8 +86,7 @@ where
         trial += 1;
         let ones = count_ones(word);
         let run_within_prob = integrate_binomial(32, 0.5, u16::from(ones));
-        let p_value = 1.0 - run_within_prob;
-        p_value
+        1.0 - run_within_prob
     })
 }
 
@@ -0,0 +1,180 @@
+//! Conducts a series of chi-squared goodness-of-fit trials on the [`Multinomial`] sampler, with
+//! different (randomly chosen) parameters on each trial. The marginal distribution of each category
+//! in a multinomial sample is binomial, with a probability equal to the category's share of the
+//! total weight. Within each trial, H0 asserts that the marginal of one (randomly chosen) category
+//! follows the binomial distribution.
+//!
+//! The support of the distribution is partitioned into bins of roughly equal probability, so that
+//! the expected frequency of each bin is large enough for the chi-squared approximation to hold.
+//! Both the inversion and the BTRD paths of the underlying binomial sampler are exercised.
+
+pub mod stats;
+
+use crate::stats::{chi_squared_p_value, holm_bonferroni_seq_correction, Rejection};
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+use statrs::distribution::DiscreteCDF;
+use std::ops::RangeInclusive;
+use tinyrand::{Counter, Distribution, Multinomial, Rand, Seeded, SplitMix, Wyrand, Xorshift};
+
+#[test]
+fn multinomial_splitmix() {
+    multinomial::<SplitMix>(Options::default()).unwrap();
+}
+
+#[test]
+fn multinomial_wyrand() {
+    multinomial::<Wyrand>(Options::default()).unwrap();
+}
+
+#[test]
+fn multinomial_xorshift() {
+    multinomial::<Xorshift>(Options::default()).unwrap();
+}
+
+#[test]
+fn multinomial_counter_should_reject() {
+    assert!(multinomial::<Counter>(Options::inversion_only()).is_err());
+}
+
+/// Options for conducting multiple trials.
+#[derive(Debug)]
+pub struct Options {
+    /// Number of randomised trials.
+    pub trials: u16,
+
+    // Samples per trial.
+    pub iters: u32,
+
+    // Target number of bins in the chi-squared test.
+    pub bins: u32,
+
+    // Range of the number of trials in the sampled distribution (chosen log-uniformly).
+    pub n_range: RangeInclusive<u64>,
+
+    // Significance level to reject H0 (stream is random). The higher the significance level, the more likely
+    // H1 (stream is nonrandom) is accepted.
+    pub significance_level: f64,
+}
+
+impl Options {
+    /// Checks that the options are valid.
+    pub fn validate(&self) {
+        assert!(self.trials > 0);
+        assert!(self.iters > 0);
+        assert!(self.bins > 1);
+        assert!(*self.n_range.start() >= 10);
+        assert!(self.significance_level >= f64::EPSILON);
+        assert!(self.significance_level <= 1.0 - f64::EPSILON);
+    }
+
+    /// Options that confine the sampler to the inversion method (used when the expected number of
+    /// successes or failures is under 10). A constant stream makes the rejection loop of BTRD spin
+    /// forever; inversion always terminates.
+    fn inversion_only() -> Self {
+        Self {
+            n_range: 10..=15,
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            trials: 100,
+            iters: 10_000,
+            bins: 20,
+            n_range: 10..=1_000_000,
+            significance_level: 0.025,
+        }
+    }
+}
+
+fn multinomial<S: Seeded>(opts: Options) -> Result<(), Vec<Rejection>> {
+    opts.validate();
+    let mut control_rng = StdRng::seed_from_u64(0);
+
+    holm_bonferroni_seq_correction(opts.significance_level, opts.trials, || {
+        let seed = control_rng.next_u64();
+        let mut rand = S::seed(seed);
+        let (n, _) = generate_params_for_test(&mut control_rng, &opts.n_range);
+        let weights: [f64; 4] = [(); 4].map(|_| control_rng.gen_range(0.1..10.0));
+        let category = control_rng.gen_range(0..weights.len());
+        let p = weights[category] / weights.iter().sum::<f64>();
+        let dist = Multinomial::new(n, weights);
+        binned_fit(&opts, &mut rand, |rand| dist.sample(rand)[category], n, p)
+    })
+}
+
+/// Draws samples using the given closure, returning the p-value of the chi-squared test against
+/// the binomial distribution with parameters `n` and `p`.
+fn binned_fit<R: Rand>(opts: &Options, rand: &mut R, mut sample: impl FnMut(&mut R) -> u64, n: u64, p: f64) -> f64 {
+    let edges = bin_edges(n, p, opts.bins);
+    let mut observed = vec![0; edges.len()];
+    for _ in 0..opts.iters {
+        let k = sample(rand);
+        assert!(k <= n, "{k} exceeds {n}");
+        let bin = edges.partition_point(|&(upper, _)| upper < k);
+        observed[bin] += 1;
+    }
+
+    let expected = edges
+        .iter()
+        .map(|&(_, prob)| prob * f64::from(opts.iters))
+        .collect::<Vec<_>>();
+    chi_squared_p_value(&observed, &expected)
+}
+
+/// Partitions `0..=n` into bins of roughly equal probability, returning the inclusive upper bound
+/// of each bin along with its probability.
+fn bin_edges(n: u64, p: f64, bins: u32) -> Vec<(u64, f64)> {
+    let dist = statrs::distribution::Binomial::new(p, n).unwrap();
+    let mean = n as f64 * p;
+    let std_dev = (mean * (1.0 - p)).sqrt();
+    let bounds = (
+        (mean - 10.0 * std_dev).max(0.0) as u64,
+        ((mean + 10.0 * std_dev).ceil() as u64).min(n),
+    );
+    let mut edges = Vec::<(u64, f64)>::new();
+    let mut cumulative = 0.0;
+    for bin in 1..bins {
+        let quantile = f64::from(bin) / f64::from(bins);
+        let upper = inverse_cdf(&dist, bounds, quantile);
+        let cdf = dist.cdf(upper);
+        if !matches!(edges.last(), Some(&(last, _)) if upper <= last) && cdf < 1.0 {
+            edges.push((upper, cdf - cumulative));
+            cumulative = cdf;
+        }
+    }
+    edges.push((n, 1.0 - cumulative));
+    edges
+}
+
+/// Finds the smallest _k_ within `bounds` such that CDF(_k_) ≥ `quantile`, by bisection.
+fn inverse_cdf(dist: &statrs::distribution::Binomial, bounds: (u64, u64), quantile: f64) -> u64 {
+    let (mut low, mut high) = bounds;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if dist.cdf(mid) >= quantile {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    low
+}
+
+/// Generates a pair comprising the number of trials and the probability of success. The parameters
+/// are chosen so that the distribution spans several bins.
+fn generate_params_for_test(rng: &mut StdRng, n_range: &RangeInclusive<u64>) -> (u64, f64) {
+    let (ln_start, ln_end) = ((*n_range.start() as f64).ln(), (*n_range.end() as f64).ln());
+    loop {
+        let n = (rng.gen_range(ln_start..=ln_end).exp().round() as u64).clamp(*n_range.start(), *n_range.end());
+        let p = rng.gen_range(0.0..1.0);
+        let variance = n as f64 * p * (1.0 - p);
+        if variance > 3.0 {
+            return (n, p);
+        }
+    }
+}
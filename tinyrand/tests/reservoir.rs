@@ -0,0 +1,132 @@
+//! Conducts a series of Bernoulli trials on the reservoir samplers, with a different (randomly chosen)
+//! stream length, reservoir size and tracked item on each trial. In each experiment, the tracked item is
+//! either included in the reservoir or not; by H0, it is included with a probability of _k_/_n_, where
+//! _k_ is the size of the reservoir and _n_ is the length of the stream.
+//! (I.e., the number of inclusions falls within a statistically acceptable interval.)
+
+pub mod stats;
+
+use crate::stats::{holm_bonferroni_seq_correction, integrate_binomial, Rejection};
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+use tinyrand::{Counter, RandIter, Seeded, SplitMix, Wyrand, Xorshift};
+
+#[test]
+fn choose_from_iter_splitmix() {
+    choose_from_iter::<SplitMix>(Options::default()).unwrap();
+}
+
+#[test]
+fn choose_from_iter_wyrand() {
+    choose_from_iter::<Wyrand>(Options::default()).unwrap();
+}
+
+#[test]
+fn choose_from_iter_xorshift() {
+    choose_from_iter::<Xorshift>(Options::default()).unwrap();
+}
+
+#[test]
+fn choose_from_iter_counter_should_reject() {
+    assert!(choose_from_iter::<Counter>(Options::default()).is_err());
+}
+
+#[test]
+fn fill_from_iter_splitmix() {
+    fill_from_iter::<SplitMix>(Options::default()).unwrap();
+}
+
+#[test]
+fn fill_from_iter_wyrand() {
+    fill_from_iter::<Wyrand>(Options::default()).unwrap();
+}
+
+#[test]
+fn fill_from_iter_xorshift() {
+    fill_from_iter::<Xorshift>(Options::default()).unwrap();
+}
+
+#[test]
+fn fill_from_iter_counter_should_reject() {
+    assert!(fill_from_iter::<Counter>(Options::default()).is_err());
+}
+
+/// Options for conducting multiple trials.
+#[derive(Debug)]
+pub struct Options {
+    /// Number of randomised trials.
+    pub trials: u16,
+
+    // Experiments per trial.
+    pub iters: u16,
+
+    // Largest stream length to test.
+    pub max_len: usize,
+
+    // Significance level to reject H0 (stream is random). The higher the significance level, the more likely
+    // H1 (stream is nonrandom) is accepted.
+    pub significance_level: f64,
+}
+
+impl Options {
+    /// Checks that the options are valid.
+    pub fn validate(&self) {
+        assert!(self.trials > 0);
+        assert!(self.iters > 0);
+        assert!(self.max_len > 1);
+        assert!(self.significance_level >= f64::EPSILON);
+        assert!(self.significance_level <= 1.0 - f64::EPSILON);
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            trials: 1000,
+            iters: 30,
+            max_len: 100,
+            significance_level: 0.025,
+        }
+    }
+}
+
+fn choose_from_iter<S: Seeded>(opts: Options) -> Result<(), Vec<Rejection>> {
+    opts.validate();
+    let mut control_rng = StdRng::seed_from_u64(0);
+
+    holm_bonferroni_seq_correction(opts.significance_level, opts.trials, || {
+        let seed = control_rng.next_u64();
+        let mut rand = S::seed(seed);
+        let n = control_rng.gen_range(2..=opts.max_len);
+        let tracked = control_rng.gen_range(0..n);
+        let mut inclusions = 0;
+        for _ in 0..opts.iters {
+            if rand.choose_from_iter(0..n) == Some(tracked) {
+                inclusions += 1;
+            }
+        }
+        1.0 - integrate_binomial(opts.iters, 1.0 / n as f64, inclusions)
+    })
+}
+
+fn fill_from_iter<S: Seeded>(opts: Options) -> Result<(), Vec<Rejection>> {
+    opts.validate();
+    let mut control_rng = StdRng::seed_from_u64(0);
+
+    holm_bonferroni_seq_correction(opts.significance_level, opts.trials, || {
+        let seed = control_rng.next_u64();
+        let mut rand = S::seed(seed);
+        let n = control_rng.gen_range(2..=opts.max_len);
+        let k = control_rng.gen_range(1..n);
+        let tracked = control_rng.gen_range(0..n);
+        let mut reservoir = vec![0; k];
+        let mut inclusions = 0;
+        for _ in 0..opts.iters {
+            assert_eq!(k, rand.fill_from_iter(0..n, &mut reservoir));
+            if reservoir.contains(&tracked) {
+                inclusions += 1;
+            }
+        }
+        1.0 - integrate_binomial(opts.iters, k as f64 / n as f64, inclusions)
+    })
+}
@@ -0,0 +1,122 @@
+//! Looks for correlations between the streams of generators seeded with adjacent seeds. Each trial
+//! draws pairs of generators seeded with _s_ and _s_ + 1 (for randomly chosen _s_), takes the `XOR`
+//! of their outputs at some position in the stream, and masks the value of a single bit,
+//! verifying that the number of times the bit is set to 1 is within the expected range. For
+//! each subsequent trial, the mask is shifted by one to the left; each cycle of 64 trials
+//! examines the next position in the stream.
+
+pub mod stats;
+
+use crate::stats::{holm_bonferroni_seq_correction, integrate_binomial, Rejection};
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+use tinyrand::scramble::Scrambled;
+use tinyrand::{Counter, Rand, Seeded, SplitMix, Wyrand, Xorshift, Xoshiro};
+
+#[test]
+fn seed_correlation_splitmix() {
+    seed_correlation::<Scrambled<SplitMix>>(Options::default()).unwrap();
+}
+
+#[test]
+fn seed_correlation_wyrand() {
+    seed_correlation::<Scrambled<Wyrand>>(Options::default()).unwrap();
+}
+
+#[test]
+fn seed_correlation_xorshift() {
+    seed_correlation::<Scrambled<Xorshift>>(Options::default()).unwrap();
+}
+
+#[test]
+fn seed_correlation_xoshiro() {
+    seed_correlation::<Scrambled<Xoshiro>>(Options::default()).unwrap();
+}
+
+#[test]
+fn seed_correlation_xoshiro_unscrambled() {
+    // Xoshiro expands its seed with SplitMix, and needs no scrambling
+    seed_correlation::<Xoshiro>(Options::default()).unwrap();
+}
+
+#[test]
+fn seed_correlation_splitmix_unscrambled_should_reject() {
+    assert!(seed_correlation::<SplitMix>(Options::default()).is_err());
+}
+
+#[test]
+fn seed_correlation_wyrand_unscrambled_should_reject() {
+    assert!(seed_correlation::<Wyrand>(Options::default()).is_err());
+}
+
+#[test]
+fn seed_correlation_xorshift_unscrambled_should_reject() {
+    assert!(seed_correlation::<Xorshift>(Options::default()).is_err());
+}
+
+#[test]
+fn seed_correlation_counter_should_reject() {
+    assert!(seed_correlation::<Counter>(Options::default()).is_err());
+}
+
+/// Options for conducting multiple trials.
+#[derive(Debug)]
+pub struct Options {
+    /// Number of trial cycles. Each cycle comprises 64 trials (one for each bit of a `u64`), at
+    /// the next position in the stream.
+    pub cycles: u16,
+
+    // Pairs of generators per trial.
+    pub iters: u16,
+
+    // Significance level to reject H0 (streams are uncorrelated). The higher the significance level, the more likely
+    // H1 (streams are correlated) is accepted.
+    pub significance_level: f64,
+}
+
+impl Options {
+    /// Checks that the options are valid.
+    pub fn validate(&self) {
+        assert!(self.cycles > 0);
+        assert!(self.iters > 0);
+        assert!(self.significance_level >= f64::EPSILON);
+        assert!(self.significance_level <= 1.0 - f64::EPSILON);
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            cycles: 10,
+            iters: 30,
+            significance_level: 0.2,
+        }
+    }
+}
+
+fn seed_correlation<S: Seeded>(opts: Options) -> Result<(), Vec<Rejection>> {
+    opts.validate();
+    let mut control_rng = StdRng::seed_from_u64(0);
+
+    let mut trial = 0;
+    holm_bonferroni_seq_correction(opts.significance_level, opts.cycles * 64, || {
+        let mask = 1u64 << (trial % 64);
+        let position = trial / 64;
+        trial += 1;
+        let mut set_bits = 0;
+        for _ in 0..opts.iters {
+            let seed = control_rng.next_u64();
+            let mut rand = S::seed(seed);
+            let mut adjacent = S::seed(seed.wrapping_add(1));
+            for _ in 0..position {
+                rand.next_u64();
+                adjacent.next_u64();
+            }
+            if (rand.next_u64() ^ adjacent.next_u64()) & mask > 0 {
+                set_bits += 1;
+            }
+        }
+        let run_within_prob = integrate_binomial(opts.iters, 0.5, set_bits);
+        1.0 - run_within_prob
+    })
+}
@@ -0,0 +1,154 @@
+//! Conducts a series of chi-squared goodness-of-fit trials on [`RandSlice::shuffle`] and
+//! [`RandSlice::partial_shuffle`], with a different (randomly chosen) slice length and prefix
+//! length on each trial. Within each trial, H0 asserts that every permutation (or, for partial
+//! shuffles, every ordered selection of _k_ elements from _n_) is equally likely.
+
+pub mod stats;
+
+use crate::stats::{chi_squared_p_value, holm_bonferroni_seq_correction, Rejection};
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+use tinyrand::{Counter, RandSlice, Seeded, SplitMix, Wyrand, Xorshift};
+
+#[test]
+fn shuffle_splitmix() {
+    shuffle::<SplitMix>(Options::default()).unwrap();
+}
+
+#[test]
+fn shuffle_wyrand() {
+    shuffle::<Wyrand>(Options::default()).unwrap();
+}
+
+#[test]
+fn shuffle_xorshift() {
+    shuffle::<Xorshift>(Options::default()).unwrap();
+}
+
+#[test]
+fn shuffle_counter_should_reject() {
+    assert!(shuffle::<Counter>(Options::default()).is_err());
+}
+
+#[test]
+fn partial_shuffle_splitmix() {
+    partial_shuffle::<SplitMix>(Options::default()).unwrap();
+}
+
+#[test]
+fn partial_shuffle_wyrand() {
+    partial_shuffle::<Wyrand>(Options::default()).unwrap();
+}
+
+#[test]
+fn partial_shuffle_xorshift() {
+    partial_shuffle::<Xorshift>(Options::default()).unwrap();
+}
+
+#[test]
+fn partial_shuffle_counter_should_reject() {
+    assert!(partial_shuffle::<Counter>(Options::default()).is_err());
+}
+
+/// Options for conducting multiple trials.
+#[derive(Debug)]
+pub struct Options {
+    /// Number of randomised trials.
+    pub trials: u16,
+
+    // Expected number of occurrences of each outcome per trial.
+    pub iters_per_outcome: u32,
+
+    // Largest slice length to test; the number of outcomes grows factorially with it.
+    pub max_len: usize,
+
+    // Significance level to reject H0 (stream is random). The higher the significance level, the more likely
+    // H1 (stream is nonrandom) is accepted.
+    pub significance_level: f64,
+}
+
+impl Options {
+    /// Checks that the options are valid.
+    pub fn validate(&self) {
+        assert!(self.trials > 0);
+        assert!(self.iters_per_outcome >= 5);
+        assert!(self.max_len >= 2);
+        assert!(self.significance_level >= f64::EPSILON);
+        assert!(self.significance_level <= 1.0 - f64::EPSILON);
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            trials: 100,
+            iters_per_outcome: 50,
+            max_len: 5,
+            significance_level: 0.025,
+        }
+    }
+}
+
+fn shuffle<S: Seeded>(opts: Options) -> Result<(), Vec<Rejection>> {
+    opts.validate();
+    let mut control_rng = StdRng::seed_from_u64(0);
+
+    holm_bonferroni_seq_correction(opts.significance_level, opts.trials, || {
+        let seed = control_rng.next_u64();
+        let mut rand = S::seed(seed);
+        let n = control_rng.gen_range(2..=opts.max_len);
+        uniformity(&opts, n, n, |slice| rand.shuffle(slice))
+    })
+}
+
+fn partial_shuffle<S: Seeded>(opts: Options) -> Result<(), Vec<Rejection>> {
+    opts.validate();
+    let mut control_rng = StdRng::seed_from_u64(0);
+
+    holm_bonferroni_seq_correction(opts.significance_level, opts.trials, || {
+        let seed = control_rng.next_u64();
+        let mut rand = S::seed(seed);
+        let n = control_rng.gen_range(2..=opts.max_len);
+        let k = control_rng.gen_range(1..=n);
+        uniformity(&opts, n, k, |slice| {
+            let chosen = rand.partial_shuffle(slice, k);
+            assert_eq!(k, chosen.len());
+        })
+    })
+}
+
+/// Repeatedly permutes the slice `[0, 1, ..., n - 1]` using the given closure, returning the p-value
+/// of the chi-squared test that every ordered selection of its first `k` elements is equally likely.
+fn uniformity(opts: &Options, n: usize, k: usize, mut permute: impl FnMut(&mut [usize])) -> f64 {
+    let outcomes = arrangements(n, k);
+    let iters = opts.iters_per_outcome as usize * outcomes;
+    let mut observed = vec![0; outcomes];
+    let mut slice = vec![0; n];
+    for _ in 0..iters {
+        for (i, element) in slice.iter_mut().enumerate() {
+            *element = i;
+        }
+        permute(&mut slice);
+        observed[rank(&slice[..k], n)] += 1;
+    }
+    let expected = vec![f64::from(opts.iters_per_outcome); outcomes];
+    chi_squared_p_value(&observed, &expected)
+}
+
+/// The number of ordered selections of `k` elements from `n`.
+fn arrangements(n: usize, k: usize) -> usize {
+    (n - k + 1..=n).product()
+}
+
+/// Ranks an ordered selection of distinct elements from `0..n` in `0..arrangements(n, k)`, using
+/// a mixed-radix (Lehmer) code.
+fn rank(selection: &[usize], n: usize) -> usize {
+    let mut used = vec![false; n];
+    let mut rank = 0;
+    for (i, &element) in selection.iter().enumerate() {
+        let digit = used[..element].iter().filter(|&&used| !used).count();
+        rank = rank * (n - i) + digit;
+        used[element] = true;
+    }
+    rank
+}
@@ -1,6 +1,6 @@
 //! Utilities for statistical hypothesis testing, combinatorics and distributions.
 
-use statrs::distribution::Discrete;
+use statrs::distribution::{ContinuousCDF, Discrete};
 
 /// Describes the rejection of a specific trial.
 #[derive(Debug)]
@@ -164,6 +164,26 @@ pub fn integrate_poisson(lambda: f64, k: u16) -> f64 {
         .min(1.0)
 }
 
+/// Computes the p-value of Pearson's chi-squared goodness-of-fit test, given the observed
+/// frequencies and the expected frequencies under H0.
+///
+/// The number of degrees of freedom is one less than the number of categories.
+pub fn chi_squared_p_value(observed: &[u64], expected: &[f64]) -> f64 {
+    assert_eq!(observed.len(), expected.len());
+    assert!(observed.len() > 1);
+
+    let statistic = observed
+        .iter()
+        .zip(expected)
+        .map(|(&observed, &expected)| {
+            let diff = observed as f64 - expected;
+            diff * diff / expected
+        })
+        .sum::<f64>();
+    let dist = statrs::distribution::ChiSquared::new((observed.len() - 1) as f64).unwrap();
+    1.0 - dist.cdf(statistic)
+}
+
 /// Obtains the Binomial Probability Mass Function.
 ///
 /// `k` — number of success outcomes (equivalently, the number of heads).
@@ -3,7 +3,7 @@
 //! If these tests were to be added as unit tests of `stats`, they would be repeated for each
 //! integration test that uses `stats`.
 
-use crate::stats::{binomial_pmf, fact, fact_trunc, ncr, poisson_pmf};
+use crate::stats::{binomial_pmf, chi_squared_p_value, fact, fact_trunc, ncr, poisson_pmf};
 
 pub mod stats;
 
@@ -18,6 +18,13 @@ fn test_poisson_pmf() {
     assert_float_epsilon(0.1754673697678507, poisson_pmf(5, 5.0));
 }
 
+#[test]
+fn test_chi_squared_p_value() {
+    assert_float_epsilon(1.0, chi_squared_p_value(&[10, 10], &[10.0, 10.0]));
+    assert_float(0.6703200460, chi_squared_p_value(&[8, 12, 10], &[10.0, 10.0, 10.0]), 1e-9);
+    assert!(chi_squared_p_value(&[0, 30, 0], &[10.0, 10.0, 10.0]) < 1e-10);
+}
+
 fn assert_float_epsilon(lhs: f64, rhs: f64) {
     assert_float(lhs, rhs, f64::EPSILON)
 }
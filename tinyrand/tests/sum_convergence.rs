@@ -1,10 +1,10 @@
 //! Conducts a series of trials on a [`Rand`] with a different (randomly chosen)
 //! integer generation range on each trial. Within each trial, H0 asserts that the source is random. (I.e.,
 //! the sum of the sampled values falls within a statistically acceptable range.)
-///
-/// Each trial computes the sum of a set of values drawn from a scaled uniform distribution. The Gaussian distribution
-/// is used as an [approximation of the Irwin-Hall distribution](https://en.wikipedia.org/wiki/Irwin%E2%80%93Hall_distribution#Approximating_a_Normal_distribution),
-/// with the unscaled mean and variance parameters set to _n_/2 and _n_/12 respectively.
+//!
+//! Each trial computes the sum of a set of values drawn from a scaled uniform distribution. The Gaussian distribution
+//! is used as an [approximation of the Irwin-Hall distribution](https://en.wikipedia.org/wiki/Irwin%E2%80%93Hall_distribution#Approximating_a_Normal_distribution),
+//! with the unscaled mean and variance parameters set to _n_/2 and _n_/12 respectively.
 
 pub mod stats;
 
@@ -12,7 +12,7 @@ use rand::rngs::StdRng;
 use rand::{RngCore, SeedableRng};
 use std::ops::Range;
 use statrs::distribution::ContinuousCDF;
-use tinyrand::{Counter, RandRange, Seeded, SplitMix, Wyrand, Xorshift};
+use tinyrand::{Counter, RandRange, Seeded, SplitMix, Wyrand, Xorshift, Xoshiro};
 use crate::stats::{holm_bonferroni_seq_correction, Rejection};
 
 #[test]
@@ -75,6 +75,26 @@ fn sum_convergence_xorshift_lag_4() {
     sum_convergence::<Xorshift>(4, Options::default()).unwrap();
 }
 
+#[test]
+fn sum_convergence_xoshiro() {
+    sum_convergence::<Xoshiro>(0, Options::default()).unwrap();
+}
+
+#[test]
+fn sum_convergence_xoshiro_lag_1() {
+    sum_convergence::<Xoshiro>(1, Options::default()).unwrap();
+}
+
+#[test]
+fn sum_convergence_xoshiro_lag_2() {
+    sum_convergence::<Xoshiro>(2, Options::default()).unwrap();
+}
+
+#[test]
+fn sum_convergence_xoshiro_lag_4() {
+    sum_convergence::<Xoshiro>(4, Options::default()).unwrap();
+}
+
 #[test]
 fn sum_convergence_counter_should_reject() {
     assert!(sum_convergence::<Counter>(0, Options::default()).is_err());
@@ -147,8 +167,7 @@ where
         let scaled_sum = (sum - u128::from(range.start) * u128::from(opts.iters)) as f64 / span;
         let distance = (scaled_sum - dist_mean).abs();
         let prob_within_distance_from_mean = dist.cdf(dist_mean + distance) - dist.cdf(dist_mean - distance);
-        let p_value = 1.0 - prob_within_distance_from_mean;
-        p_value
+        1.0 - prob_within_distance_from_mean
     })
 }
 
@@ -0,0 +1,165 @@
+//! Conducts a series of chi-squared goodness-of-fit trials on the [`Zipf`] and [`PowerLaw`]
+//! samplers, with a different (randomly chosen) range and exponent on each trial. Within each
+//! trial, H0 asserts that the sampled values follow the power law. (I.e., the frequencies of the
+//! smallest values in the range are within a statistically acceptable distance of those expected.)
+//!
+//! Because the normalisation constant of a power law over a very large range is impractical to
+//! compute, each trial only examines the first few values of the range, conditioning the expected
+//! frequencies on the number of samples that landed there. For any range, the conditional probability
+//! of _k_ is proportional to _k_<sup>-_s_</sup>.
+
+pub mod stats;
+
+use crate::stats::{chi_squared_p_value, holm_bonferroni_seq_correction, Rejection};
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+use std::ops::Range;
+use tinyrand::{Counter, Distribution, PowerLaw, Rand, Seeded, SplitMix, Wyrand, Xorshift, Zipf};
+
+#[test]
+fn zipf_splitmix() {
+    zipf::<SplitMix>(Options::default()).unwrap();
+}
+
+#[test]
+fn zipf_wyrand() {
+    zipf::<Wyrand>(Options::default()).unwrap();
+}
+
+#[test]
+fn zipf_xorshift() {
+    zipf::<Xorshift>(Options::default()).unwrap();
+}
+
+#[test]
+fn zipf_counter_should_reject() {
+    assert!(zipf::<Counter>(Options::default()).is_err());
+}
+
+#[test]
+fn power_law_splitmix() {
+    power_law::<SplitMix>(Options::default()).unwrap();
+}
+
+#[test]
+fn power_law_wyrand() {
+    power_law::<Wyrand>(Options::default()).unwrap();
+}
+
+#[test]
+fn power_law_xorshift() {
+    power_law::<Xorshift>(Options::default()).unwrap();
+}
+
+#[test]
+fn power_law_counter_should_reject() {
+    assert!(power_law::<Counter>(Options::default()).is_err());
+}
+
+/// Options for conducting multiple trials.
+#[derive(Debug)]
+pub struct Options {
+    /// Number of randomised trials.
+    pub trials: u16,
+
+    // Samples per trial.
+    pub iters: u32,
+
+    // Number of categories (the smallest values in the range) in the chi-squared test.
+    pub categories: u64,
+
+    // Significance level to reject H0 (stream is random). The higher the significance level, the more likely
+    // H1 (stream is nonrandom) is accepted.
+    pub significance_level: f64,
+}
+
+impl Options {
+    /// Checks that the options are valid.
+    pub fn validate(&self) {
+        assert!(self.trials > 0);
+        assert!(self.iters > 0);
+        assert!(self.categories > 1);
+        assert!(self.significance_level >= f64::EPSILON);
+        assert!(self.significance_level <= 1.0 - f64::EPSILON);
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            trials: 100,
+            iters: 20_000,
+            categories: 10,
+            significance_level: 0.025,
+        }
+    }
+}
+
+fn zipf<S: Seeded>(opts: Options) -> Result<(), Vec<Rejection>> {
+    opts.validate();
+    let mut control_rng = StdRng::seed_from_u64(0);
+
+    holm_bonferroni_seq_correction(opts.significance_level, opts.trials, || {
+        let seed = control_rng.next_u64();
+        let mut rand = S::seed(seed);
+        let (n, s) = generate_params_for_test(&mut control_rng);
+        let dist = Zipf::new(n, s);
+        conditional_fit(&opts, &mut rand, &dist, 1..n.saturating_add(1), s)
+    })
+}
+
+fn power_law<S: Seeded>(opts: Options) -> Result<(), Vec<Rejection>> {
+    opts.validate();
+    let mut control_rng = StdRng::seed_from_u64(0);
+
+    holm_bonferroni_seq_correction(opts.significance_level, opts.trials, || {
+        let seed = control_rng.next_u64();
+        let mut rand = S::seed(seed);
+        let (width, exponent) = generate_params_for_test(&mut control_rng);
+        let start = control_rng.gen_range(1..1_000u64);
+        let range = start..start.saturating_add(width);
+        let dist = PowerLaw::new(range.clone(), exponent);
+        conditional_fit(&opts, &mut rand, &dist, range, exponent)
+    })
+}
+
+/// Samples from the given distribution, returning the p-value of the chi-squared test over the
+/// first `opts.categories` values of the range.
+fn conditional_fit(opts: &Options, rand: &mut impl Rand, dist: &impl Distribution<u64>, range: Range<u64>, exponent: f64) -> f64 {
+    let categories = opts.categories.min(range.end - range.start);
+    let mut observed = vec![0; categories as usize];
+    for _ in 0..opts.iters {
+        let k = dist.sample(rand);
+        assert!(range.contains(&k), "{k} outside of {range:?}");
+        let offset = k - range.start;
+        if offset < categories {
+            observed[offset as usize] += 1;
+        }
+    }
+
+    let weights = (0..categories)
+        .map(|offset| ((range.start + offset) as f64).powf(-exponent))
+        .collect::<Vec<_>>();
+    let total_weight = weights.iter().sum::<f64>();
+    let total_observed = observed.iter().sum::<u64>() as f64;
+    if total_observed == 0.0 {
+        // nothing to condition on; the sampler either has a broken head or an extremely long tail
+        return 0.0;
+    }
+    let expected = weights
+        .into_iter()
+        .map(|weight| weight / total_weight * total_observed)
+        .collect::<Vec<_>>();
+    chi_squared_p_value(&observed, &expected)
+}
+
+/// Generates a pair comprising the width of the range and the exponent. Either the range is
+/// small, so that it is entirely covered by the test, or it is vast, in which case the exponent is
+/// chosen so that enough samples land at the head of the distribution.
+fn generate_params_for_test(rng: &mut StdRng) -> (u64, f64) {
+    if rng.gen_bool(0.5) {
+        (rng.gen_range(2..=10), rng.gen_range(0.0..3.0))
+    } else {
+        (rng.gen_range(u64::MAX >> 1..=u64::MAX), rng.gen_range(1.1..3.0))
+    }
+}